@@ -0,0 +1,335 @@
+//! Capabilities which grant less than a whole owner.
+//!
+//! A [`ReadOwner`] grants read access to the cells of one group, and
+//! nothing more. It is made from a borrowed owner, and can be copied and
+//! stored by components which should only ever read the group:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::capability::ReadOwner;
+//!
+//! def_cells! {
+//!     [pub mod] config: TCellPubGrp;
+//! }
+//!
+//! struct Renderer<'o> {
+//!     config: ReadOwner<'o, config::PubMarker>
+//! }
+//!
+//! let owner = config::new_public_owner();
+//! let scale = config::new_public_cell(2.0);
+//! let renderer = Renderer { config: ReadOwner::new(&owner) };
+//!
+//! assert_eq!(*scale.ro(&renderer.config), 2.0);
+//! c_scp! {
+//!     use &renderer.config => ( scale => *factor ) {
+//!         assert_eq!(factor, 2.0);
+//!     }
+//! }
+//! ```
+//!
+//! Since it dereferences to the owner immutably, and never mutably, the
+//! `mut` forms of [`c_scp`] reject it at compile time:
+//!
+//! ```rust,compile_fail
+//! # use cell_wrappers::*;
+//! # use cell_wrappers::capability::ReadOwner;
+//! # def_cells! {
+//! #     [pub mod] config: TCellPubGrp;
+//! # }
+//! let owner = config::new_public_owner();
+//! let scale = config::new_public_cell(2.0);
+//! let mut reader: ReadOwner<config::PubMarker> = ReadOwner::new(&owner);
+//! c_scp! {
+//!     use &mut reader => ( scale => mut factor ) {
+//!         *factor = 3.0;
+//!     }
+//! }
+//! ```
+//!
+//! A group which is only written while it is set up can be frozen once
+//! that is done. [`freeze`] consumes the group's owner, and returns a
+//! [`FrozenOwner`] which reads the group from anywhere, for the rest of the
+//! process. For [`TCell`] groups, it is `Clone`, `Send` and `Sync`, and no
+//! other owner of the group can be created while the frozen one exists,
+//! so the group can never be written again:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::capability::{freeze, FrozenOwner};
+//!
+//! def_cells! {
+//!     [pub mod] config: TCellPubGrp;
+//! }
+//!
+//! let mut owner = config::new_public_owner();
+//! let scale = config::new_public_cell(1.0);
+//! *scale.rw(&mut owner) = 2.0;
+//!
+//! let frozen: FrozenOwner<config::PubMarker> = freeze(owner);
+//! std::thread::scope(|threads| {
+//!     for _ in 0..4 {
+//!         let (frozen, scale) = (frozen.clone(), &scale);
+//!         threads.spawn(move || assert_eq!(*frozen.ro(scale), 2.0));
+//!     }
+//! });
+//! ```
+//!
+//! Freezing requires the `alloc` feature.
+//!
+//! [`ReadOwner`]: ./struct.ReadOwner.html
+//! [`c_scp`]: ../macro.c_scp.html
+//! [`freeze`]: ./fn.freeze.html
+//! [`FrozenOwner`]: ./struct.FrozenOwner.html
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{CellGroup, OwnedCell};
+
+/// Grants read access to the cells of the group `G`, through an owner
+/// which is borrowed for `'o`.
+pub struct ReadOwner<'o, G>
+where
+    G : CellGroup
+{
+    owner : &'o G::Owner
+}
+
+impl<'o, G> ReadOwner<'o, G>
+where
+    G : CellGroup
+{
+    #[inline]
+    pub fn new(owner : &'o G::Owner) -> Self {
+        Self { owner }
+    }
+
+    /// Reads `cell`, for as long as the owner is borrowed. Cells whose
+    /// owner is wrapped by the group's owner, such as those of groups with
+    /// `owners: newtype`, are read with their own `ro` method instead.
+    #[inline]
+    pub fn ro<C>(&self, cell : &'o C) -> &'o C::Value
+    where
+        C : OwnedCell<Owner = G::Owner> + ?Sized
+    {
+        cell.ro(self.owner)
+    }
+
+    /// Returns the borrowed owner, which can only be used for reading.
+    #[inline]
+    pub fn owner(&self) -> &'o G::Owner {
+        self.owner
+    }
+}
+
+impl<G> Clone for ReadOwner<'_, G>
+where
+    G : CellGroup
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for ReadOwner<'_, G> where G : CellGroup {}
+
+impl<G> Deref for ReadOwner<'_, G>
+where
+    G : CellGroup
+{
+    type Target = G::Owner;
+
+    #[inline]
+    fn deref(&self) -> &G::Owner {
+        self.owner
+    }
+}
+
+impl<'o, G> From<&'o G::Owner> for ReadOwner<'o, G>
+where
+    G : CellGroup
+{
+    #[inline]
+    fn from(owner : &'o G::Owner) -> Self {
+        Self::new(owner)
+    }
+}
+
+impl<G> fmt::Debug for ReadOwner<'_, G>
+where
+    G : CellGroup
+{
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadOwner").finish_non_exhaustive()
+    }
+}
+
+/// Grants read access to the cells of the group `G`, from anywhere, for the
+/// rest of the process. It is made by [`freeze`].
+///
+/// [`freeze`]: ./fn.freeze.html
+#[cfg(feature = "alloc")]
+pub struct FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    owner : &'static G::Owner
+}
+
+/// Consumes the owner of the group `G`, and keeps it for the rest of the
+/// process, behind a [`FrozenOwner`] which can only read the group.
+///
+/// The owner is never dropped, so the hooks of owners declared with
+/// `owners: newtype` do not see it released.
+///
+/// [`FrozenOwner`]: ./struct.FrozenOwner.html
+#[cfg(feature = "alloc")]
+pub fn freeze<G>(owner : G::Owner) -> FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    FrozenOwner { owner : alloc::boxed::Box::leak(alloc::boxed::Box::new(owner)) }
+}
+
+#[cfg(feature = "alloc")]
+impl<G> FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    /// Reads `cell`, which can be borrowed for as long as needed, since the
+    /// owner is never released.
+    #[inline]
+    pub fn ro<'a, C>(&self, cell : &'a C) -> &'a C::Value
+    where
+        C : OwnedCell<Owner = G::Owner> + ?Sized
+    {
+        cell.ro(self.owner)
+    }
+
+    /// Returns a [`ReadOwner`] of the frozen owner.
+    ///
+    /// [`ReadOwner`]: ./struct.ReadOwner.html
+    #[inline]
+    pub fn reader(&self) -> ReadOwner<'static, G> {
+        ReadOwner::new(self.owner)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G> Clone for FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G> Copy for FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{}
+
+#[cfg(feature = "alloc")]
+impl<G> Deref for FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    type Target = G::Owner;
+
+    #[inline]
+    fn deref(&self) -> &G::Owner {
+        self.owner
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G> fmt::Debug for FrozenOwner<G>
+where
+    G : CellGroup,
+    G::Owner : 'static
+{
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenOwner").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{c_scp, def_cells};
+
+    def_cells! {
+        [mod] cap_grp: TCellAccGrp;
+        [mod] cap_tl_grp: TLCellUniGrp with { owners: newtype, cells: newtype };
+        [mod] frozen_grp: TCellPubGrp;
+    }
+
+    struct Panel<'o> {
+        theme: ReadOwner<'o, cap_grp::PubMarker>,
+        layout: ReadOwner<'o, cap_tl_grp::UniMarker>
+    }
+
+    impl Panel<'_> {
+        fn describe(&self, color: &cap_grp::PubCell<&'static str>, width: &cap_tl_grp::UniCell<u32>) -> String {
+            format!("{} x{}", self.theme.ro(color), width.ro(&self.layout))
+        }
+    }
+
+    #[test]
+    fn read_owner_tests() {
+        let mut pub_owner = cap_grp::new_public_owner();
+        let tl_owner = cap_tl_grp::new_uniform_owner();
+        let color = cap_grp::new_public_cell("red");
+        let width = cap_tl_grp::UniCell::new(3);
+
+        let panel = Panel { theme: ReadOwner::new(&pub_owner), layout: (&tl_owner).into() };
+        let copied = panel.theme;
+        assert_eq!(panel.describe(&color, &width), "red x3");
+        assert_eq!(*color.ro(&copied), "red");
+        c_scp! {
+            let scoped = &panel.layout => ( width => *read_width ) {
+                assert_eq!(read_width, 3);
+                assert_eq!(*width.ro(scoped), 3);
+            }
+        }
+        assert_eq!(format!("{:?}", copied), "ReadOwner { .. }");
+
+        *color.rw(&mut pub_owner) = "blue";
+        assert_eq!(*ReadOwner::<cap_grp::PubMarker>::new(&pub_owner).ro(&color), "blue");
+    }
+
+    #[test]
+    fn frozen_owner_tests() {
+        let mut owner = frozen_grp::new_public_owner();
+        let limits = [frozen_grp::new_public_cell(1), frozen_grp::new_public_cell(2)];
+        *limits[1].rw(&mut owner) = 20;
+
+        let frozen: FrozenOwner<frozen_grp::PubMarker> = freeze(owner);
+        assert!(qcell::TCellOwner::<frozen_grp::PubMarker>::try_new().is_none());
+
+        let total: i32 = std::thread::scope(|threads| {
+            let spawned: Vec<_> = limits.iter()
+                .map(|limit| threads.spawn(move || *frozen.ro(limit)))
+                .collect();
+            spawned.into_iter().map(|thread| thread.join().unwrap()).sum()
+        });
+        assert_eq!(total, 21);
+
+        let reader = frozen.reader();
+        assert_eq!(*reader.ro(&limits[0]), 1);
+        assert_eq!(*limits[1].ro(&frozen), 20);
+        assert_eq!(format!("{:?}", frozen.clone()), "FrozenOwner { .. }");
+    }
+}
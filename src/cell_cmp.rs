@@ -0,0 +1,265 @@
+//! Comparison and hashing adapters, which read the contents of cells with an
+//! owner.
+//!
+//! The cells of [`qcell`] can't implement [`PartialEq`], [`Ord`] or
+//! [`Hash`] on their own, since their contents can only be read with an
+//! owner. The [`eq_with`], [`cmp_with`] and [`hash_with`] methods compare
+//! or hash two cells of one group under a single owner, and a [`Keyed`]
+//! pairs a cell with a borrowed owner, so that it can be sorted,
+//! deduplicated or kept in a `HashSet` by its contents:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::cell_cmp::{CmpWith, Keyed};
+//! use std::collections::HashSet;
+//! use std::rc::Rc;
+//!
+//! def_cells! {
+//!     [pub mod] tags: TCellUniGrp;
+//! }
+//!
+//! let owner = tags::new_uniform_owner();
+//! let mut list: Vec<Rc<tags::UniCell<&str>>> = ["b", "a", "b"].into_iter()
+//!     .map(|tag| Rc::new(tags::new_uniform_cell(tag)))
+//!     .collect();
+//!
+//! assert!(list[0].eq_with(&owner, &list[2]));
+//! list.sort_by(|a, b| a.cmp_with(&owner, b));
+//! list.dedup_by(|a, b| a.eq_with(&owner, b));
+//! assert_eq!(list.len(), 2);
+//!
+//! let unique: HashSet<_> = ["x", "y", "x"].into_iter()
+//!     .map(|tag| Keyed::new(&owner, Rc::new(tags::new_uniform_cell(tag))))
+//!     .collect();
+//! assert_eq!(unique.len(), 2);
+//! ```
+//!
+//! A [`Keyed`] holds its owner by shared reference, so none of the cells of
+//! the group can be mutated while it exists, and the order or hash of its
+//! contents can't change while it is in a sorted collection or a set.
+//!
+//! [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
+//! [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+//! [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+//! [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+//! [`eq_with`]: ./trait.CmpWith.html#method.eq_with
+//! [`cmp_with`]: ./trait.CmpWith.html#method.cmp_with
+//! [`hash_with`]: ./trait.CmpWith.html#method.hash_with
+//! [`Keyed`]: ./struct.Keyed.html
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::OwnedCell;
+
+/// This `trait` provides [`eq_with`], [`partial_cmp_with`], [`cmp_with`]
+/// and [`hash_with`] for every [`OwnedCell`].
+///
+/// [`eq_with`]: #method.eq_with
+/// [`partial_cmp_with`]: #method.partial_cmp_with
+/// [`cmp_with`]: #method.cmp_with
+/// [`hash_with`]: #method.hash_with
+/// [`OwnedCell`]: ../trait.OwnedCell.html
+pub trait CmpWith : OwnedCell {
+    /// Checks whether the contents of two cells are equal, by reading both
+    /// with `owner`.
+    #[inline]
+    fn eq_with(&self, owner : &Self::Owner, other : &Self) -> bool
+    where
+        Self::Value : PartialEq
+    {
+        self.ro(owner) == other.ro(owner)
+    }
+
+    /// Compares the contents of two cells, by reading both with `owner`.
+    #[inline]
+    fn partial_cmp_with(&self, owner : &Self::Owner, other : &Self) -> Option<Ordering>
+    where
+        Self::Value : PartialOrd
+    {
+        self.ro(owner).partial_cmp(other.ro(owner))
+    }
+
+    /// Compares the contents of two cells, by reading both with `owner`.
+    #[inline]
+    fn cmp_with(&self, owner : &Self::Owner, other : &Self) -> Ordering
+    where
+        Self::Value : Ord
+    {
+        self.ro(owner).cmp(other.ro(owner))
+    }
+
+    /// Feeds the contents of the cell into `state`, by reading them with
+    /// `owner`.
+    #[inline]
+    fn hash_with<H : Hasher>(&self, owner : &Self::Owner, state : &mut H)
+    where
+        Self::Value : Hash
+    {
+        self.ro(owner).hash(state)
+    }
+}
+
+impl<C> CmpWith for C where C : OwnedCell + ?Sized {}
+
+/// A handle to a cell, such as a reference or an `Rc`, paired with a
+/// borrowed owner, which is compared and hashed by the contents of the
+/// cell.
+pub struct Keyed<'o, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>
+{
+    owner : &'o O,
+    cell : C
+}
+
+impl<'o, O, C> Keyed<'o, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>
+{
+    #[inline]
+    pub fn new(owner : &'o O, cell : C) -> Self {
+        Self { owner, cell }
+    }
+
+    /// Returns the handle to the cell.
+    #[inline]
+    pub fn cell(&self) -> &C {
+        &self.cell
+    }
+
+    /// Reads the contents of the cell with the borrowed owner.
+    #[inline]
+    pub fn get(&self) -> &<C::Target as OwnedCell>::Value {
+        self.cell.ro(self.owner)
+    }
+
+    /// Returns the handle to the cell, and releases the owner.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.cell
+    }
+}
+
+impl<O, C> Clone for Keyed<'_, O, C>
+where
+    C : Deref + Clone,
+    C::Target : OwnedCell<Owner = O>
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { owner : self.owner, cell : self.cell.clone() }
+    }
+}
+
+impl<O, C> PartialEq for Keyed<'_, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>,
+    <C::Target as OwnedCell>::Value : PartialEq
+{
+    #[inline]
+    fn eq(&self, other : &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<O, C> Eq for Keyed<'_, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>,
+    <C::Target as OwnedCell>::Value : Eq
+{}
+
+impl<O, C> PartialOrd for Keyed<'_, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>,
+    <C::Target as OwnedCell>::Value : PartialOrd
+{
+    #[inline]
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        self.get().partial_cmp(other.get())
+    }
+}
+
+impl<O, C> Ord for Keyed<'_, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>,
+    <C::Target as OwnedCell>::Value : Ord
+{
+    #[inline]
+    fn cmp(&self, other : &Self) -> Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+impl<O, C> Hash for Keyed<'_, O, C>
+where
+    C : Deref,
+    C::Target : OwnedCell<Owner = O>,
+    <C::Target as OwnedCell>::Value : Hash
+{
+    #[inline]
+    fn hash<H : Hasher>(&self, state : &mut H) {
+        self.get().hash(state)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+    use std::collections::{BTreeSet, HashSet};
+    use std::collections::hash_map::DefaultHasher;
+    use std::rc::Rc;
+
+    def_cells! {
+        [mod] cmp_grp: TCellAccGrp;
+        [mod] cmp_tl_grp: TLCellUniGrp with { cells: newtype };
+    }
+
+    fn hash_of(cell : &cmp_grp::PubCell<u32>, owner : &cmp_grp::PubOwner) -> u64 {
+        let mut state = DefaultHasher::new();
+        cell.hash_with(owner, &mut state);
+        state.finish()
+    }
+
+    // The cells in a `Keyed` can't be mutated while their owner is borrowed.
+    #[allow(clippy::mutable_key_type)]
+    #[test]
+    fn cell_cmp_tests() {
+        let pub_owner = cmp_grp::new_public_owner();
+        let (a, b, c) = (cmp_grp::new_public_cell(2), cmp_grp::new_public_cell(2), cmp_grp::new_public_cell(5));
+
+        assert!(a.eq_with(&pub_owner, &b));
+        assert!(!a.eq_with(&pub_owner, &c));
+        assert_eq!(a.cmp_with(&pub_owner, &c), Ordering::Less);
+        assert_eq!(c.partial_cmp_with(&pub_owner, &b), Some(Ordering::Greater));
+        assert_eq!(hash_of(&a, &pub_owner), hash_of(&b, &pub_owner));
+
+        let pvt_owner = cmp_grp::new_private_owner();
+        let nan = cmp_grp::new_private_cell(f64::NAN);
+        assert_eq!(nan.partial_cmp_with(&pvt_owner, &nan), None);
+
+        let tl_owner = cmp_tl_grp::new_uniform_owner();
+        let mut words: Vec<Rc<cmp_tl_grp::UniCell<String>>> = ["pear", "fig", "pear", "apple"].into_iter()
+            .map(|word| Rc::new(cmp_tl_grp::UniCell::new(word.into())))
+            .collect();
+        words.sort_by(|x, y| x.cmp_with(&tl_owner, y));
+        words.dedup_by(|x, y| x.eq_with(&tl_owner, y));
+        let sorted: Vec<&str> = words.iter().map(|word| word.ro(&tl_owner).as_str()).collect();
+        assert_eq!(sorted, ["apple", "fig", "pear"]);
+
+        let unique: HashSet<_> = [&a, &b, &c].into_iter().map(|cell| Keyed::new(&pub_owner, cell)).collect();
+        assert_eq!(unique.len(), 2);
+        let ordered: BTreeSet<_> = words.iter().cloned().map(|word| Keyed::new(&tl_owner, word)).collect();
+        let last = ordered.last().unwrap().clone().into_inner();
+        assert!(Rc::ptr_eq(&last, &words[2]));
+        assert_eq!(ordered.first().unwrap().get(), "apple");
+    }
+}
@@ -0,0 +1,199 @@
+//! Formatting adapters, which show the contents of cells with an owner.
+//!
+//! The cells of [`qcell`] can't implement [`Debug`] or [`Display`] on their
+//! own, since their contents can only be read with an owner. The
+//! [`debug_with`] and [`display_with`] methods pair a cell with an owner
+//! for formatting, and the [`fmt_cells`] and [`dbg_cell`] macros build on
+//! them:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::cell_fmt::FmtWith;
+//!
+//! def_cells! {
+//!     [pub mod] stats: TCellUniGrp;
+//! }
+//!
+//! let owner = stats::new_uniform_owner();
+//! let hits = stats::new_uniform_cell(3);
+//! let name = stats::new_uniform_cell("main");
+//!
+//! assert_eq!(format!("{:?}", hits.debug_with(&owner)), "3");
+//! assert_eq!(format!("{}", name.display_with(&owner)), "main");
+//! assert_eq!(format!("{:?}", fmt_cells!(owner; hits, name)), r#"{hits: 3, name: "main"}"#);
+//! ```
+//!
+//! [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
+//! [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+//! [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+//! [`debug_with`]: ./trait.FmtWith.html#method.debug_with
+//! [`display_with`]: ./trait.FmtWith.html#method.display_with
+//! [`fmt_cells`]: ../macro.fmt_cells.html
+//! [`dbg_cell`]: ../macro.dbg_cell.html
+
+use core::fmt;
+
+use crate::OwnedCell;
+
+/// A cell, paired with an owner, which is formatted with the [`Debug`]
+/// implementation of its contents.
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+pub struct DebugWith<'a, C>
+where
+    C : OwnedCell + ?Sized
+{
+    cell : &'a C,
+    owner : &'a C::Owner
+}
+
+impl<C> fmt::Debug for DebugWith<'_, C>
+where
+    C : OwnedCell + ?Sized,
+    C::Value : fmt::Debug
+{
+    #[inline]
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.cell.ro(self.owner), f)
+    }
+}
+
+/// A cell, paired with an owner, which is formatted with the [`Display`]
+/// implementation of its contents.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+pub struct DisplayWith<'a, C>
+where
+    C : OwnedCell + ?Sized
+{
+    cell : &'a C,
+    owner : &'a C::Owner
+}
+
+impl<C> fmt::Display for DisplayWith<'_, C>
+where
+    C : OwnedCell + ?Sized,
+    C::Value : fmt::Display
+{
+    #[inline]
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.cell.ro(self.owner), f)
+    }
+}
+
+/// This `trait` provides [`debug_with`] and [`display_with`] for every
+/// [`OwnedCell`].
+///
+/// [`debug_with`]: #method.debug_with
+/// [`display_with`]: #method.display_with
+/// [`OwnedCell`]: ../trait.OwnedCell.html
+pub trait FmtWith : OwnedCell {
+    /// Pairs the cell with `owner`, so that its contents can be printed
+    /// with `{:?}`.
+    #[inline]
+    fn debug_with<'a>(&'a self, owner : &'a Self::Owner) -> DebugWith<'a, Self> {
+        DebugWith { cell : self, owner }
+    }
+
+    /// Pairs the cell with `owner`, so that its contents can be printed
+    /// with `{}`.
+    #[inline]
+    fn display_with<'a>(&'a self, owner : &'a Self::Owner) -> DisplayWith<'a, Self> {
+        DisplayWith { cell : self, owner }
+    }
+}
+
+impl<C> FmtWith for C where C : OwnedCell + ?Sized {}
+
+/// Formats with a function, which is used by [`fmt_cells`] to format
+/// several cells at once.
+///
+/// [`fmt_cells`]: ../macro.fmt_cells.html
+pub struct FmtFn<F>(pub F)
+where
+    F : Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+impl<F> fmt::Debug for FmtFn<F>
+where
+    F : Fn(&mut fmt::Formatter<'_>) -> fmt::Result
+{
+    #[inline]
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+impl<F> fmt::Display for FmtFn<F>
+where
+    F : Fn(&mut fmt::Formatter<'_>) -> fmt::Result
+{
+    #[inline]
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// A label which is written as it is, without quotes, such as the names
+/// of the cells which are shown by [`fmt_cells`].
+///
+/// [`fmt_cells`]: ../macro.fmt_cells.html
+pub struct Label<'a>(pub &'a str);
+
+impl fmt::Debug for Label<'_> {
+    #[inline]
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{dbg_cell, def_cells, fmt_cells};
+
+    def_cells! {
+        [mod] fmt_grp: TCellAccGrp;
+        [mod] fmt_tl_grp: TLCellUniGrp with { cells: newtype };
+        [mod] fmt_q_grp: QCellUniGrp;
+    }
+
+    struct Panel {
+        title: fmt_grp::PubCell<&'static str>,
+        width: fmt_tl_grp::UniCell<u32>
+    }
+
+    #[test]
+    fn cell_fmt_tests() {
+        let pub_owner = fmt_grp::new_public_owner();
+        let tl_owner = fmt_tl_grp::new_uniform_owner();
+        let panel = Panel {
+            title: fmt_grp::new_public_cell("log"),
+            width: fmt_tl_grp::UniCell::new(80)
+        };
+
+        assert_eq!(format!("{:?}", panel.title.debug_with(&pub_owner)), "\"log\"");
+        assert_eq!(format!("{}", panel.title.display_with(&pub_owner)), "log");
+        assert_eq!(format!("{:>4}", panel.width.display_with(&tl_owner)), "  80");
+        assert_eq!(
+            format!("{}", fmt_cells!(pub_owner; panel.title)),
+            "{panel.title: \"log\"}"
+        );
+        assert_eq!(
+            format!("{:#?}", fmt_cells!(tl_owner; panel.width)),
+            "{\n    panel.width: 80,\n}"
+        );
+
+        let boxed: Box<fmt_grp::PvtCell<dyn fmt::Debug>> = Box::new(fmt_grp::new_private_cell(1.5));
+        assert_eq!(format!("{:?}", boxed.debug_with(&fmt_grp::new_private_owner())), "1.5");
+
+        let q_owner = fmt_q_grp::new_uniform_owner();
+        let q_cell = fmt_q_grp::new_uniform_cell(&q_owner, vec![1]);
+        assert_eq!(format!("{:?}", q_cell.debug_with(&q_owner)), "[1]");
+
+        let status = fmt_grp::new_public_cell("ok");
+        let width = dbg_cell!(tl_owner; &panel.width);
+        let (title, status) = dbg_cell!(pub_owner; &panel.title, &status,);
+        assert_eq!(*width.ro(&tl_owner), 80);
+        assert_eq!((*title.ro(&pub_owner), *status.ro(&pub_owner)), ("log", "ok"));
+    }
+}
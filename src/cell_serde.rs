@@ -0,0 +1,298 @@
+//! [`serde`] support for cells, which can only be read under an owner.
+//!
+//! A single cell is serialized with [`serialize_with`], which pairs it with
+//! an owner of its group:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::cell_serde::SerializeWith;
+//!
+//! def_cells! {
+//!     [pub mod] ui: TCellPubGrp;
+//! }
+//!
+//! let owner = ui::new_public_owner();
+//! let volume: ui::PubCell<u8> = ui::new_public_cell(7);
+//! let _serializable = volume.serialize_with(&owner);
+//! ```
+//!
+//! For a `struct` which contains cells, each cell field is given the
+//! `#[serde(with = "cell_wrappers::cell_serde")]` attribute, and the
+//! `struct` is serialized through a [`WithOwner`], which holds the owner
+//! that the fields are read with. Deserializing such a `struct` builds a
+//! fresh cell for each field, and needs no owner at all:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::cell_serde::WithOwner;
+//! use serde::{Deserialize, Serialize};
+//!
+//! def_cells! {
+//!     [pub mod] ui: TCellPubGrp;
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "cell_wrappers::cell_serde")]
+//!     volume: ui::PubCell<u8>,
+//!     name: String
+//! }
+//!
+//! let owner = ui::new_public_owner();
+//! let settings = Settings { volume: ui::new_public_cell(7), name: "main".into() };
+//! let _serializable = WithOwner::new(&owner, &settings);
+//! ```
+//!
+//! A field is serialized with the innermost [`WithOwner`] whose owner
+//! matches the field's cell, so [`WithOwner`]s can be nested for `struct`s
+//! which hold cells of several groups. If there is none, then serializing
+//! fails with an error. Owners declared with `owners: newtype` are passed
+//! to [`WithOwner::new`] as the owner that they wrap, such as `&*owner`.
+//!
+//! There is no `#[cell_serde]` field attribute. An attribute like that
+//! would need a procedural macro crate, while this crate only provides
+//! `macro_rules!` macros, and it could only expand to the same
+//! `#[serde(with = "cell_wrappers::cell_serde")]` that serde already
+//! accepts, so that form is the supported way of marking cell fields.
+//!
+//! This module requires the `serde` feature.
+//!
+//! [`serde`]: https://docs.rs/serde/latest/serde/index.html
+//! [`serialize_with`]: ./trait.SerializeWith.html#method.serialize_with
+
+use std::any::TypeId;
+use std::cell::RefCell;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Error, Serialize, Serializer};
+
+use crate::OwnedCell;
+
+/// This `trait` describes an [`OwnedCell`] which can be built without an
+/// owner, so that it can be deserialized. It is implemented for [`TCell`]s
+/// and [`TLCell`]s, and for the cells declared by [`def_cells`] with
+/// `cells: newtype`.
+///
+/// [`OwnedCell`]: ../trait.OwnedCell.html
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`def_cells`]: ../macro.def_cells.html
+pub trait BuildCell : OwnedCell {
+    fn from_value(value : Self::Value) -> Self where Self::Value : Sized;
+}
+
+impl<Q, T> BuildCell for qcell::TCell<Q, T> where Q : 'static {
+    #[inline]
+    fn from_value(value : T) -> Self {
+        qcell::TCell::new(value)
+    }
+}
+
+impl<Q, T> BuildCell for qcell::TLCell<Q, T> where Q : 'static {
+    #[inline]
+    fn from_value(value : T) -> Self {
+        qcell::TLCell::new(value)
+    }
+}
+
+/// A cell, paired with an owner that it is serialized under.
+pub struct SerializeCell<'a, C>
+where
+    C : OwnedCell + ?Sized
+{
+    cell : &'a C,
+    owner : &'a C::Owner
+}
+
+impl<C> Serialize for SerializeCell<'_, C>
+where
+    C : OwnedCell + ?Sized,
+    C::Value : Serialize
+{
+    #[inline]
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.cell.ro(self.owner).serialize(serializer)
+    }
+}
+
+/// This `trait` provides [`serialize_with`] for every [`OwnedCell`].
+///
+/// [`serialize_with`]: #method.serialize_with
+/// [`OwnedCell`]: ../trait.OwnedCell.html
+pub trait SerializeWith : OwnedCell {
+    /// Pairs the cell with `owner`, so that it can be serialized.
+    #[inline]
+    fn serialize_with<'a>(&'a self, owner : &'a Self::Owner) -> SerializeCell<'a, Self> {
+        SerializeCell { cell : self, owner }
+    }
+}
+
+impl<C> SerializeWith for C where C : OwnedCell + ?Sized {}
+
+std::thread_local! {
+    // The owners of every `WithOwner` that is being serialized on this thread.
+    static SCOPED_OWNERS : RefCell<Vec<(TypeId, *const ())>> = const { RefCell::new(Vec::new()) };
+}
+
+// Removes the owner of a `WithOwner` from scope, even if serializing panics.
+struct ScopeGuard;
+
+impl Drop for ScopeGuard {
+    #[inline]
+    fn drop(&mut self) {
+        SCOPED_OWNERS.with(|owners| owners.borrow_mut().pop());
+    }
+}
+
+/// A value which is serialized with an owner in scope, for any of its cell
+/// fields which have the `#[serde(with = "cell_wrappers::cell_serde")]`
+/// attribute, and belong to the owner's group.
+pub struct WithOwner<'o, O, S>
+where
+    O : 'static,
+    S : ?Sized
+{
+    owner : &'o O,
+    value : &'o S
+}
+
+impl<'o, O, S> WithOwner<'o, O, S>
+where
+    O : 'static,
+    S : ?Sized
+{
+    #[inline]
+    pub fn new(owner : &'o O, value : &'o S) -> Self {
+        Self { owner, value }
+    }
+}
+
+impl<O, S> Serialize for WithOwner<'_, O, S>
+where
+    O : 'static,
+    S : Serialize + ?Sized
+{
+    fn serialize<Z : Serializer>(&self, serializer : Z) -> Result<Z::Ok, Z::Error> {
+        let owner = self.owner as *const O as *const ();
+        SCOPED_OWNERS.with(|owners| owners.borrow_mut().push((TypeId::of::<O>(), owner)));
+        let _guard = ScopeGuard;
+        self.value.serialize(serializer)
+    }
+}
+
+/// Serializes `cell` with the innermost [`WithOwner`] of its group, for use
+/// with `#[serde(with = "cell_wrappers::cell_serde")]`.
+pub fn serialize<C, S>(cell : &C, serializer : S) -> Result<S::Ok, S::Error>
+where
+    C : OwnedCell + ?Sized,
+    C::Owner : 'static,
+    C::Value : Serialize,
+    S : Serializer
+{
+    let owner = SCOPED_OWNERS.with(|owners| {
+        owners.borrow().iter().rev()
+            .find(|(id, _)| *id == TypeId::of::<C::Owner>())
+            .map(|(_, owner)| *owner as *const C::Owner)
+    });
+    match owner {
+        // SAFETY: The owner was placed in scope by a `WithOwner`, which
+        // borrows it until the `ScopeGuard` that removes it is dropped, and
+        // that happens after this nested call to `serialize` has returned.
+        Some(owner) => cell.serialize_with(unsafe { &*owner }).serialize(serializer),
+        None => Err(S::Error::custom(
+            "no owner of this cell's group is in scope, so it must be serialized through a `WithOwner`"
+        ))
+    }
+}
+
+/// Deserializes the contents of a cell, and builds a fresh cell around them,
+/// for use with `#[serde(with = "cell_wrappers::cell_serde")]`.
+#[inline]
+pub fn deserialize<'de, C, D>(deserializer : D) -> Result<C, D::Error>
+where
+    C : BuildCell,
+    C::Value : Deserialize<'de> + Sized,
+    D : Deserializer<'de>
+{
+    C::Value::deserialize(deserializer).map(C::from_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens, assert_ser_tokens, assert_ser_tokens_error, Token};
+
+    def_cells! {
+        [mod] serde_grp: TCellAccGrp;
+        [mod] serde_tl_grp: TLCellUniGrp with { cells: newtype };
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct State {
+        #[serde(with = "crate::cell_serde")]
+        count: serde_grp::PubCell<u32>,
+        #[serde(with = "crate::cell_serde")]
+        secret: serde_grp::PvtCell<bool>,
+        #[serde(with = "crate::cell_serde")]
+        label: serde_tl_grp::UniCell<String>
+    }
+
+    // Cells can only be compared under owners, so the owners of the test
+    // must be dropped before this is used.
+    impl PartialEq for State {
+        fn eq(&self, other: &Self) -> bool {
+            let pub_owner = serde_grp::new_public_owner();
+            let pvt_owner = serde_grp::new_private_owner();
+            let tl_owner = serde_tl_grp::new_uniform_owner();
+            self.count.ro(&pub_owner) == other.count.ro(&pub_owner)
+                && self.secret.ro(&pvt_owner) == other.secret.ro(&pvt_owner)
+                && self.label.ro(&tl_owner) == other.label.ro(&tl_owner)
+        }
+    }
+
+    impl std::fmt::Debug for State {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("State").finish_non_exhaustive()
+        }
+    }
+
+    const STATE_TOKENS: [Token; 8] = [
+        Token::Struct { name: "State", len: 3 },
+        Token::Str("count"), Token::U32(4),
+        Token::Str("secret"), Token::Bool(true),
+        Token::Str("label"), Token::Str("main"),
+        Token::StructEnd
+    ];
+
+    fn new_state() -> State {
+        State {
+            count: serde_grp::new_public_cell(4),
+            secret: serde_grp::new_private_cell(true),
+            label: serde_tl_grp::UniCell::new("main".into())
+        }
+    }
+
+    #[test]
+    fn cell_serde_tests() {
+        let pub_owner = serde_grp::new_public_owner();
+        let pvt_owner = serde_grp::new_private_owner();
+        let tl_owner = serde_tl_grp::new_uniform_owner();
+        let state = new_state();
+
+        let with_tl = WithOwner::new(&tl_owner, &state);
+        let with_pvt = WithOwner::new(&pvt_owner, &with_tl);
+        assert_ser_tokens(&WithOwner::new(&pub_owner, &with_pvt), &STATE_TOKENS);
+        assert_ser_tokens_error(
+            &WithOwner::new(&pub_owner, &with_tl),
+            &STATE_TOKENS[..4],
+            "no owner of this cell's group is in scope, so it must be serialized through a `WithOwner`"
+        );
+        assert_ser_tokens(&state.count.serialize_with(&pub_owner), &[Token::U32(4)]);
+        assert_ser_tokens(&state.label.serialize_with(&tl_owner), &[Token::Str("main")]);
+
+        drop((pub_owner, pvt_owner, tl_owner));
+        assert_de_tokens(&new_state(), &STATE_TOKENS);
+    }
+}
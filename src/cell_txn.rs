@@ -0,0 +1,167 @@
+//! Snapshots of cells, which [`c_txn`] restores when a transaction fails.
+//!
+//! This module requires the `std` feature.
+//!
+//! [`c_txn`]: ../macro.c_txn.html
+
+use crate::OwnedCell;
+
+/// Restores a cell to the contents that it held when its snapshot was taken.
+pub type Rollback<'a, O> = Box<dyn FnOnce(&mut O) + 'a>;
+
+/// This `trait` provides [`snapshot`] for every [`OwnedCell`] whose
+/// contents can be cloned.
+///
+/// [`snapshot`]: #method.snapshot
+/// [`OwnedCell`]: ../trait.OwnedCell.html
+pub trait SnapshotCell : OwnedCell {
+    /// Clones the contents of the cell with `owner`, and returns a
+    /// [`Rollback`] which writes them back into the cell.
+    ///
+    /// [`Rollback`]: ./type.Rollback.html
+    fn snapshot<'a>(&'a self, owner : &Self::Owner) -> Rollback<'a, Self::Owner>
+    where
+        Self::Value : Clone + Sized + 'a
+    {
+        let saved = self.ro(owner).clone();
+        Box::new(move |owner : &mut Self::Owner| *self.rw(owner) = saved)
+    }
+}
+
+impl<C> SnapshotCell for C where C : OwnedCell + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{c_txn, def_cells, impl_get_uni};
+
+    def_cells! {
+        [mod] txn_grp: TCellUniGrp;
+        [mod] txn_tl_grp: TLCellUniGrp with { cells: newtype };
+        [mod] txn_nt_grp: TLCellUniGrp with { owners: newtype };
+        [mod] txn_self_grp: TLCellUniGrp;
+    }
+
+    struct Player {
+        hp: txn_grp::UniCell<i32>,
+        gold: txn_grp::UniCell<u32>,
+        log: txn_grp::UniCell<Vec<&'static str>>
+    }
+
+    fn buy(player: &Player, owner: &mut txn_grp::UniOwner, price: u32) -> Result<u32, &'static str> {
+        c_txn! {
+            let txn_owner = &mut *owner => (player.log, player.gold => mut gold) {
+                *gold = gold.checked_sub(price).ok_or("not enough gold")?;
+                let left = *gold;
+                player.log.rw(txn_owner).push("buy");
+                Ok(left)
+            }
+        }
+    }
+
+    struct Inventory {
+        slots: txn_self_grp::UniCell<Vec<&'static str>>,
+        weight: txn_self_grp::UniCell<u32>
+    }
+
+    impl_get_uni!(Inventory => txn_self_grp::UniOwner);
+
+    impl Inventory {
+        fn pick_up(&self, item: &'static str, item_weight: u32) -> Result<usize, u32> {
+            c_txn! {
+                let inventory_owner = [self] => (self.weight => *weight, self.slots => mut slots) {
+                    slots.push(item);
+                    let count = slots.len();
+                    *self.weight.rw(inventory_owner) = weight + item_weight;
+                    if weight + item_weight > 10 {
+                        return Err(weight + item_weight);
+                    }
+                    Ok(count)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cell_txn_tests() {
+        let mut owner = txn_grp::new_uniform_owner();
+        let player = Player {
+            hp: txn_grp::new_uniform_cell(10),
+            gold: txn_grp::new_uniform_cell(7),
+            log: txn_grp::new_uniform_cell(Vec::new())
+        };
+
+        assert_eq!(buy(&player, &mut owner, 5), Ok(2));
+        assert_eq!(buy(&player, &mut owner, 5), Err("not enough gold"));
+        assert_eq!(*player.gold.ro(&owner), 2);
+        assert_eq!(*player.log.ro(&owner), ["buy"]);
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            c_txn! {
+                use &mut owner => (player.hp, player.gold,) {
+                    *player.hp.rw(&mut owner) -= 4;
+                    *player.gold.rw(&mut owner) += 1;
+                    if *player.hp.ro(&owner) > 0 {
+                        panic!("the potion was a mimic");
+                    }
+                    Ok::<(), ()>(())
+                }
+            }
+        }));
+        assert!(panicked.is_err());
+        assert_eq!((*player.hp.ro(&owner), *player.gold.ro(&owner)), (10, 2));
+
+        let label = txn_tl_grp::UniCell::new(String::from("main"));
+        let renamed: Result<(), String> = c_txn! {
+            use _ => (label => mut name: &mut String) {
+                name.push_str("_2");
+                Err(name.clone())
+            }
+        };
+        assert_eq!(renamed, Err(String::from("main_2")));
+        assert_eq!(label.ro(&txn_tl_grp::new_uniform_owner()), "main");
+
+        let depth = txn_nt_grp::new_uniform_cell(1);
+        let deeper: Result<i32, ()> = c_txn! {
+            let nt_owner = (txn_nt_grp::new_uniform_owner()) => (depth => *old_depth) {
+                *depth.rw(nt_owner) = old_depth + 1;
+                Ok(*depth.ro(nt_owner))
+            }
+        };
+        assert_eq!(deeper, Ok(2));
+
+        let inventory = Inventory {
+            slots: txn_self_grp::new_uniform_cell(vec!["map"]),
+            weight: txn_self_grp::new_uniform_cell(2)
+        };
+        *inventory.weight.rw(&mut txn_self_grp::new_uniform_owner()) = 4;
+        assert_eq!(inventory.pick_up("rope", 3), Ok(2));
+        assert_eq!(inventory.pick_up("anvil", 8), Err(15));
+        c_txn! {
+            use txn_self_grp::UniOwner => (inventory.slots => slots, inventory.weight => *weight) {
+                assert_eq!((slots.as_slice(), weight), (["map", "rope"].as_slice(), 7));
+                Ok::<(), ()>(())
+            }
+        }.unwrap();
+    }
+
+    #[test]
+    fn cell_txn_single_eval_test() {
+        let mut owner = txn_grp::new_uniform_owner();
+        let cells = [txn_grp::new_uniform_cell(1), txn_grp::new_uniform_cell(2)];
+        let picks = std::cell::Cell::new(0);
+        let pick = || {
+            picks.set(picks.get() + 1);
+            &cells[picks.get() - 1]
+        };
+
+        let result: Result<(), i32> = c_txn! {
+            use &mut owner => (pick() => mut picked) {
+                *picked += 10;
+                Err(*picked)
+            }
+        };
+        assert_eq!(result, Err(11));
+        assert_eq!(picks.get(), 1);
+        assert_eq!((*cells[0].ro(&owner), *cells[1].ro(&owner)), (1, 2));
+    }
+}
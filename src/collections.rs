@@ -0,0 +1,451 @@
+//! Collections of cells which are keyed to a single group, so that every
+//! element can be reached through one owner of that group.
+//!
+//! Each element of a [`CellVec`] or [`CellMap`] is stored in its own cell
+//! of the group `G`, which is the marker of a [`TCell`] or [`TLCell`] group,
+//! such as `my_grp::UniMarker`. Reading the elements takes a shared borrow
+//! of the group's owner, and writing them takes a mutable one, so a single
+//! owner can mutate every element in one pass:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::collections::CellVec;
+//!
+//! def_cells! {
+//!     [pub mod] scores: TCellUniGrp;
+//! }
+//!
+//! let mut owner = scores::new_uniform_owner();
+//! let mut list: CellVec<scores::UniMarker, u32> = CellVec::new();
+//! list.push(1);
+//! list.push(2);
+//!
+//! for score in list.iter_mut(&mut owner) {
+//!     *score *= 10;
+//! }
+//! list.retain(&mut owner, |score| *score > 10);
+//! assert_eq!(list.iter(&owner).collect::<Vec<_>>(), [&20]);
+//! ```
+//!
+//! Adding or removing elements takes `&mut self` instead of an owner, since
+//! it never exposes the contents of the other elements.
+//!
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+//! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{CellGroup, GroupOwner};
+
+/// The cell `type` of the group `G`, holding a `T`.
+pub type CellOf<G, T> = <<G as CellGroup>::Owner as GroupOwner>::Cell<T>;
+
+/// A `Vec` of cells which all belong to the group `G`.
+pub struct CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    cells : Vec<CellOf<G, T>>
+}
+
+impl<G, T> CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates an empty `CellVec`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { cells : Vec::new() }
+    }
+
+    /// Creates an empty `CellVec`, with space for at least `capacity` elements.
+    #[inline]
+    pub fn with_capacity(capacity : usize) -> Self {
+        Self { cells : Vec::with_capacity(capacity) }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Places `item` in a new cell at the end of the `CellVec`.
+    #[inline]
+    pub fn push(&mut self, item : T) {
+        self.cells.push(G::Owner::new_cell(item));
+    }
+
+    /// Removes the last element, and returns its contents.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.cells.pop().map(G::Owner::into_inner)
+    }
+
+    /// Places `item` in a new cell at `index`, shifting the later elements.
+    #[inline]
+    pub fn insert(&mut self, index : usize, item : T) {
+        self.cells.insert(index, G::Owner::new_cell(item));
+    }
+
+    /// Removes the element at `index`, and returns its contents.
+    #[inline]
+    pub fn remove(&mut self, index : usize) -> T {
+        G::Owner::into_inner(self.cells.remove(index))
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// The cells of this `CellVec`, which can be passed to [`c_scp`] one at a time.
+    ///
+    /// [`c_scp`]: ../macro.c_scp.html
+    #[inline]
+    pub fn cells(&self) -> &[CellOf<G, T>] {
+        &self.cells
+    }
+
+    #[inline]
+    pub fn get<'a>(&'a self, owner : &'a G::Owner, index : usize) -> Option<&'a T> {
+        self.cells.get(index).map(|cell| owner.ro(cell))
+    }
+
+    #[inline]
+    pub fn get_mut<'a>(&'a self, owner : &'a mut G::Owner, index : usize) -> Option<&'a mut T> {
+        self.cells.get(index).map(|cell| owner.rw(cell))
+    }
+
+    #[inline]
+    pub fn iter<'a>(&'a self, owner : &'a G::Owner) -> impl Iterator<Item = &'a T> + 'a {
+        self.cells.iter().map(move |cell| owner.ro(cell))
+    }
+
+    /// Iterates over every element mutably, at the same time.
+    #[inline]
+    pub fn iter_mut<'a>(&'a self, owner : &'a mut G::Owner) -> impl Iterator<Item = &'a mut T> + 'a {
+        self.cells.iter().map(move |cell| {
+            let item : *mut T = owner.rw(cell);
+            // SAFETY: The owner is borrowed mutably for all of `'a`, so no
+            // other reference into a cell of this group can exist, and each
+            // cell of this `CellVec` is visited once. `GroupOwner` requires
+            // that `rw` never aliases distinct cells, so the references
+            // which are handed out never alias. The pointer comes from the
+            // cell itself, and is valid for as long as `self` is borrowed.
+            unsafe { &mut *item }
+        })
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, passing each of
+    /// them to `f` mutably.
+    #[inline]
+    pub fn retain<F>(&mut self, owner : &mut G::Owner, mut f : F)
+    where
+        F : FnMut(&mut T) -> bool
+    {
+        self.cells.retain(|cell| f(owner.rw(cell)));
+    }
+
+    /// Takes the contents out of every cell.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells.into_iter().map(G::Owner::into_inner).collect()
+    }
+}
+
+impl<G, T> Default for CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, T> From<Vec<T>> for CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn from(items : Vec<T>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+impl<G, T> FromIterator<T> for CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn from_iter<I : IntoIterator<Item = T>>(items : I) -> Self {
+        Self { cells : items.into_iter().map(G::Owner::new_cell).collect() }
+    }
+}
+
+impl<G, T> Extend<T> for CellVec<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn extend<I : IntoIterator<Item = T>>(&mut self, items : I) {
+        self.cells.extend(items.into_iter().map(G::Owner::new_cell));
+    }
+}
+
+/// A `HashMap` whose values are cells which all belong to the group `G`.
+pub struct CellMap<G, K, V>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    cells : HashMap<K, CellOf<G, V>>
+}
+
+impl<G, K, V> CellMap<G, K, V>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner,
+    K : Hash + Eq
+{
+    /// Creates an empty `CellMap`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { cells : HashMap::new() }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key : &Q) -> bool
+    where
+        K : Borrow<Q>,
+        Q : Hash + Eq + ?Sized
+    {
+        self.cells.contains_key(key)
+    }
+
+    /// Places `value` in a new cell under `key`, and returns the contents of
+    /// the cell that it replaced, if any.
+    #[inline]
+    pub fn insert(&mut self, key : K, value : V) -> Option<V> {
+        self.cells.insert(key, G::Owner::new_cell(value)).map(G::Owner::into_inner)
+    }
+
+    /// Removes the cell under `key`, and returns its contents.
+    #[inline]
+    pub fn remove<Q>(&mut self, key : &Q) -> Option<V>
+    where
+        K : Borrow<Q>,
+        Q : Hash + Eq + ?Sized
+    {
+        self.cells.remove(key).map(G::Owner::into_inner)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// The cell under `key`, which can be passed to [`c_scp`].
+    ///
+    /// [`c_scp`]: ../macro.c_scp.html
+    #[inline]
+    pub fn cell<Q>(&self, key : &Q) -> Option<&CellOf<G, V>>
+    where
+        K : Borrow<Q>,
+        Q : Hash + Eq + ?Sized
+    {
+        self.cells.get(key)
+    }
+
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.cells.keys()
+    }
+
+    #[inline]
+    pub fn get<'a, Q>(&'a self, owner : &'a G::Owner, key : &Q) -> Option<&'a V>
+    where
+        K : Borrow<Q>,
+        Q : Hash + Eq + ?Sized
+    {
+        self.cells.get(key).map(|cell| owner.ro(cell))
+    }
+
+    #[inline]
+    pub fn get_mut<'a, Q>(&'a self, owner : &'a mut G::Owner, key : &Q) -> Option<&'a mut V>
+    where
+        K : Borrow<Q>,
+        Q : Hash + Eq + ?Sized
+    {
+        self.cells.get(key).map(|cell| owner.rw(cell))
+    }
+
+    #[inline]
+    pub fn iter<'a>(&'a self, owner : &'a G::Owner) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        self.cells.iter().map(move |(key, cell)| (key, owner.ro(cell)))
+    }
+
+    /// Iterates over every entry, with mutable values, at the same time.
+    #[inline]
+    pub fn iter_mut<'a>(
+        &'a self,
+        owner : &'a mut G::Owner
+    ) -> impl Iterator<Item = (&'a K, &'a mut V)> + 'a {
+        self.cells.iter().map(move |(key, cell)| {
+            let value : *mut V = owner.rw(cell);
+            // SAFETY: See `CellVec::iter_mut`, since every key of the map
+            // holds a distinct cell.
+            (key, unsafe { &mut *value })
+        })
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, passing each of
+    /// their values to `f` mutably.
+    #[inline]
+    pub fn retain<F>(&mut self, owner : &mut G::Owner, mut f : F)
+    where
+        F : FnMut(&K, &mut V) -> bool
+    {
+        self.cells.retain(|key, cell| f(key, owner.rw(cell)));
+    }
+
+    /// Takes the contents out of every cell.
+    #[inline]
+    pub fn into_hash_map(self) -> HashMap<K, V> {
+        self.cells.into_iter().map(|(key, cell)| (key, G::Owner::into_inner(cell))).collect()
+    }
+}
+
+impl<G, K, V> Default for CellMap<G, K, V>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner,
+    K : Hash + Eq
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G, K, V> FromIterator<(K, V)> for CellMap<G, K, V>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner,
+    K : Hash + Eq
+{
+    #[inline]
+    fn from_iter<I : IntoIterator<Item = (K, V)>>(entries : I) -> Self {
+        Self {
+            cells : entries.into_iter()
+                .map(|(key, value)| (key, G::Owner::new_cell(value)))
+                .collect()
+        }
+    }
+}
+
+impl<G, K, V> Extend<(K, V)> for CellMap<G, K, V>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner,
+    K : Hash + Eq
+{
+    #[inline]
+    fn extend<I : IntoIterator<Item = (K, V)>>(&mut self, entries : I) {
+        self.cells.extend(entries.into_iter().map(|(key, value)| (key, G::Owner::new_cell(value))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+
+    def_cells! {
+        [mod] vec_grp: TCellUniGrp;
+        [mod] map_grp: TLCellPubGrp;
+        [mod] hooked_grp: TLCellUniGrp with { owners: newtype };
+    }
+
+    #[test]
+    fn cell_vec_tests() {
+        let mut owner = vec_grp::new_uniform_owner();
+        let mut list: CellVec<vec_grp::UniMarker, i32> = (1..=4).collect();
+        list.insert(0, 0);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.get(&owner, 0), Some(&0));
+
+        let mut refs: Vec<&mut i32> = list.iter_mut(&mut owner).collect();
+        let last = refs.pop().unwrap();
+        std::mem::swap(refs[1], last);
+        *refs[0] = 10;
+        assert_eq!(list.iter(&owner).copied().collect::<Vec<_>>(), [10, 4, 2, 3, 1]);
+
+        *list.get_mut(&mut owner, 2).unwrap() += 1;
+        list.retain(&mut owner, |item| {
+            *item += 1;
+            *item % 2 == 0
+        });
+        assert_eq!(list.cells().len(), 3);
+        assert_eq!(list.pop(), Some(2));
+        list.extend([7]);
+        assert_eq!(list.remove(0), 4);
+        assert_eq!(list.into_vec(), [4, 7]);
+    }
+
+    #[test]
+    fn cell_map_tests() {
+        let mut owner = map_grp::new_public_owner();
+        let mut map: CellMap<map_grp::PubMarker, &str, Vec<u8>> = CellMap::new();
+        assert!(map.is_empty());
+        map.insert("a", vec![1]);
+        map.insert("b", vec![2]);
+        assert_eq!(map.insert("b", vec![3]), Some(vec![2]));
+        assert!(map.contains_key("a"));
+
+        for (key, value) in map.iter_mut(&mut owner) {
+            value.extend(key.bytes());
+        }
+        assert_eq!(map.get(&owner, "a"), Some(&vec![1, b'a']));
+        map.get_mut(&mut owner, "b").unwrap().clear();
+        map.retain(&mut owner, |_, value| !value.is_empty());
+        assert_eq!(map.keys().collect::<Vec<_>>(), [&"a"]);
+        assert_eq!(map.cell("a").map(|cell| cell.ro(&owner).len()), Some(2));
+        assert_eq!(map.remove("a"), Some(vec![1, b'a']));
+        assert!(map.into_hash_map().is_empty());
+    }
+
+    #[test]
+    fn newtype_owner_collection_tests() {
+        let mut owner = hooked_grp::UniOwner::new();
+        let list: CellVec<hooked_grp::UniMarker, i32> = vec![1, 2].into();
+        list.iter_mut(&mut owner).for_each(|item| *item = -*item);
+        assert_eq!(list.iter(&owner).sum::<i32>(), -3);
+
+        let map: CellMap<hooked_grp::UniMarker, u8, i32> = [(1, 1)].into_iter().collect();
+        assert_eq!(map.iter(&owner).collect::<Vec<_>>(), [(&1, &1)]);
+    }
+}
@@ -0,0 +1,583 @@
+//! Linked structures whose nodes are cells of a single group, so that the
+//! whole structure is read and rewired through one owner of that group.
+//!
+//! A [`CellArena`] stores a forest of nodes, each found by a stable
+//! [`NodeId`], and linked to a parent and a list of children. A [`CellList`]
+//! is a doubly-linked list, and serves as a reference for building linked
+//! structures out of the `Rc` and `Weak` pointers of cells. In both, the
+//! links are kept in the cells alongside the values, so changing them only
+//! needs the owner, and traversals borrow the owner too:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::graph::CellArena;
+//!
+//! def_cells! {
+//!     [pub mod] scene: TCellUniGrp;
+//! }
+//!
+//! let mut owner = scene::new_uniform_owner();
+//! let mut arena: CellArena<scene::UniMarker, &str> = CellArena::new();
+//! let root = arena.insert("root");
+//! let camera = arena.insert("camera");
+//! let light = arena.insert("light");
+//! arena.append_child(&mut owner, root, camera);
+//! arena.append_child(&mut owner, root, light);
+//!
+//! let names: Vec<&str> = arena.descendants(&owner, root)
+//!     .map(|id| *arena.get(&owner, id).unwrap())
+//!     .collect();
+//! assert_eq!(names, ["root", "camera", "light"]);
+//! ```
+//!
+//! As with the [`collections`], the group `G` is given by its marker.
+//!
+//! [`collections`]: ../collections/index.html
+
+use std::rc::{Rc, Weak};
+
+use crate::collections::CellOf;
+use crate::{CellGroup, GroupOwner};
+
+/// A handle to a node of a [`CellArena`].
+///
+/// Handles stay valid until their node is removed, and a handle to a
+/// removed node is never given to a later node. A slot which has held
+/// `u32::MAX` nodes is retired, rather than reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index : usize,
+    generation : u32
+}
+
+#[derive(Clone, Copy, Default)]
+struct Links {
+    parent : Option<NodeId>,
+    first_child : Option<NodeId>,
+    last_child : Option<NodeId>,
+    prev_sibling : Option<NodeId>,
+    next_sibling : Option<NodeId>
+}
+
+struct Node<T> {
+    value : T,
+    links : Links
+}
+
+struct Slot<C> {
+    generation : u32,
+    cell : Option<C>
+}
+
+/// A forest of nodes which all belong to the group `G`.
+pub struct CellArena<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    slots : Vec<Slot<CellOf<G, Node<T>>>>,
+    free : Vec<usize>,
+    retired : usize
+}
+
+impl<G, T> CellArena<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates an empty `CellArena`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { slots : Vec::new(), free : Vec::new(), retired : 0 }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len() - self.retired
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether `id` still refers to a node of this arena.
+    #[inline]
+    pub fn contains(&self, id : NodeId) -> bool {
+        self.cell(id).is_some()
+    }
+
+    /// Places `value` in a new node, with no parent or children.
+    pub fn insert(&mut self, value : T) -> NodeId {
+        let cell = G::Owner::new_cell(Node { value, links : Links::default() });
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.cell = Some(cell);
+                NodeId { index, generation : slot.generation }
+            },
+            None => {
+                self.slots.push(Slot { generation : 0, cell : Some(cell) });
+                NodeId { index : self.slots.len() - 1, generation : 0 }
+            }
+        }
+    }
+
+    /// Removes the node of `id`, and returns its value. The children of
+    /// the node are detached, and become roots.
+    pub fn remove(&mut self, owner : &mut G::Owner, id : NodeId) -> Option<T> {
+        if !self.contains(id) {
+            return None;
+        }
+        self.detach(owner, id);
+        let mut child = self.links(owner, id).first_child;
+        while let Some(current) = child {
+            let links = self.links_mut(owner, current);
+            child = links.next_sibling;
+            links.parent = None;
+            links.prev_sibling = None;
+            links.next_sibling = None;
+        }
+
+        let slot = &mut self.slots[id.index];
+        match slot.generation.checked_add(1) {
+            Some(generation) => {
+                slot.generation = generation;
+                self.free.push(id.index);
+            },
+            None => self.retired += 1
+        }
+        slot.cell.take().map(|cell| G::Owner::into_inner(cell).value)
+    }
+
+    #[inline]
+    pub fn get<'a>(&'a self, owner : &'a G::Owner, id : NodeId) -> Option<&'a T> {
+        self.cell(id).map(|cell| &owner.ro(cell).value)
+    }
+
+    #[inline]
+    pub fn get_mut<'a>(&'a self, owner : &'a mut G::Owner, id : NodeId) -> Option<&'a mut T> {
+        self.cell(id).map(|cell| &mut owner.rw(cell).value)
+    }
+
+    /// Every node of the arena, in no particular order.
+    pub fn iter<'a>(&'a self, owner : &'a G::Owner) -> impl Iterator<Item = (NodeId, &'a T)> + 'a {
+        self.slots.iter().enumerate().filter_map(move |(index, slot)| {
+            let cell = slot.cell.as_ref()?;
+            Some((NodeId { index, generation : slot.generation }, &owner.ro(cell).value))
+        })
+    }
+
+    /// Moves `child` to the end of the children of `parent`, detaching it
+    /// from its previous parent first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not in this arena, or if `child` is `parent`
+    /// or one of its ancestors.
+    pub fn append_child(&self, owner : &mut G::Owner, parent : NodeId, child : NodeId) {
+        assert!(self.contains(parent) && self.contains(child), "node is not in this arena");
+        assert!(
+            !self.ancestors(owner, parent).chain(Some(parent)).any(|id| id == child),
+            "a node cannot be appended to itself or to its descendants"
+        );
+
+        self.detach(owner, child);
+        let last = self.links(owner, parent).last_child;
+        {
+            let links = self.links_mut(owner, child);
+            links.parent = Some(parent);
+            links.prev_sibling = last;
+        }
+        match last {
+            Some(last) => self.links_mut(owner, last).next_sibling = Some(child),
+            None => self.links_mut(owner, parent).first_child = Some(child)
+        }
+        self.links_mut(owner, parent).last_child = Some(child);
+    }
+
+    /// Unlinks `id` from its parent and siblings, which makes it a root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not in this arena.
+    pub fn detach(&self, owner : &mut G::Owner, id : NodeId) {
+        let Links { parent, prev_sibling, next_sibling, .. } = *self.links(owner, id);
+        match prev_sibling {
+            Some(prev) => self.links_mut(owner, prev).next_sibling = next_sibling,
+            None => if let Some(parent) = parent {
+                self.links_mut(owner, parent).first_child = next_sibling;
+            }
+        }
+        match next_sibling {
+            Some(next) => self.links_mut(owner, next).prev_sibling = prev_sibling,
+            None => if let Some(parent) = parent {
+                self.links_mut(owner, parent).last_child = prev_sibling;
+            }
+        }
+
+        let links = self.links_mut(owner, id);
+        links.parent = None;
+        links.prev_sibling = None;
+        links.next_sibling = None;
+    }
+
+    /// The parent of `id`, if it has one.
+    #[inline]
+    pub fn parent(&self, owner : &G::Owner, id : NodeId) -> Option<NodeId> {
+        self.cell(id).and_then(|cell| owner.ro(cell).links.parent)
+    }
+
+    /// The children of `id`, in order.
+    pub fn children<'a>(&'a self, owner : &'a G::Owner, id : NodeId) -> impl Iterator<Item = NodeId> + 'a {
+        let first = self.cell(id).and_then(|cell| owner.ro(cell).links.first_child);
+        std::iter::successors(first, move |&child| self.links(owner, child).next_sibling)
+    }
+
+    /// The parent of `id`, followed by its parent, and so on up to a root.
+    pub fn ancestors<'a>(&'a self, owner : &'a G::Owner, id : NodeId) -> impl Iterator<Item = NodeId> + 'a {
+        std::iter::successors(self.parent(owner, id), move |&ancestor| self.parent(owner, ancestor))
+    }
+
+    /// The node of `id`, followed by all of its descendants, in depth-first
+    /// pre-order.
+    pub fn descendants<'a>(&'a self, owner : &'a G::Owner, id : NodeId) -> impl Iterator<Item = NodeId> + 'a {
+        let start = Some(id).filter(|&id| self.contains(id));
+        std::iter::successors(start, move |&node| {
+            let links = self.links(owner, node);
+            if links.first_child.is_some() {
+                return links.first_child;
+            }
+            let mut current = node;
+            while current != id {
+                let links = self.links(owner, current);
+                if links.next_sibling.is_some() {
+                    return links.next_sibling;
+                }
+                current = links.parent?;
+            }
+            None
+        })
+    }
+
+    #[inline]
+    fn cell(&self, id : NodeId) -> Option<&CellOf<G, Node<T>>> {
+        match self.slots.get(id.index) {
+            Some(Slot { generation, cell : Some(cell) }) if *generation == id.generation => Some(cell),
+            _ => None
+        }
+    }
+
+    #[inline]
+    fn links<'a>(&'a self, owner : &'a G::Owner, id : NodeId) -> &'a Links {
+        &owner.ro(self.cell(id).expect("node is not in this arena")).links
+    }
+
+    #[inline]
+    fn links_mut<'a>(&'a self, owner : &'a mut G::Owner, id : NodeId) -> &'a mut Links {
+        &mut owner.rw(self.cell(id).expect("node is not in this arena")).links
+    }
+}
+
+impl<G, T> Default for CellArena<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type ListCell<G, T> = CellOf<G, ListNode<G, T>>;
+
+struct ListNode<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    value : T,
+    prev : Option<Weak<ListCell<G, T>>>,
+    next : Option<Rc<ListCell<G, T>>>
+}
+
+/// A doubly-linked list, whose nodes are cells of the group `G`.
+///
+/// Each node holds an `Rc` of the next node, and a `Weak` of the previous
+/// one, so the list can be walked, and grown or shrunk, from either end.
+pub struct CellList<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    head : Option<Rc<ListCell<G, T>>>,
+    tail : Option<Rc<ListCell<G, T>>>,
+    len : usize
+}
+
+impl<G, T> CellList<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates an empty `CellList`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { head : None, tail : None, len : 0 }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, owner : &mut G::Owner, value : T) {
+        let next = self.head.take();
+        let node = Rc::new(G::Owner::new_cell(ListNode { value, prev : None, next : next.clone() }));
+        match next {
+            Some(next) => owner.rw(&next).prev = Some(Rc::downgrade(&node)),
+            None => self.tail = Some(node.clone())
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, owner : &mut G::Owner, value : T) {
+        let prev = self.tail.take();
+        let node = Rc::new(G::Owner::new_cell(ListNode {
+            value,
+            prev : prev.as_ref().map(Rc::downgrade),
+            next : None
+        }));
+        match prev {
+            Some(prev) => owner.rw(&prev).next = Some(node.clone()),
+            None => self.head = Some(node.clone())
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self, owner : &mut G::Owner) -> Option<T> {
+        let head = self.head.take()?;
+        match owner.rw(&head).next.take() {
+            Some(next) => {
+                owner.rw(&next).prev = None;
+                self.head = Some(next);
+            },
+            None => self.tail = None
+        }
+        self.len -= 1;
+        Some(Self::unwrap_node(head))
+    }
+
+    pub fn pop_back(&mut self, owner : &mut G::Owner) -> Option<T> {
+        let tail = self.tail.take()?;
+        match owner.rw(&tail).prev.take().and_then(|prev| prev.upgrade()) {
+            Some(prev) => {
+                owner.rw(&prev).next = None;
+                self.tail = Some(prev);
+            },
+            None => self.head = None
+        }
+        self.len -= 1;
+        Some(Self::unwrap_node(tail))
+    }
+
+    #[inline]
+    pub fn front<'a>(&'a self, owner : &'a G::Owner) -> Option<&'a T> {
+        self.head.as_ref().map(|head| &owner.ro(head).value)
+    }
+
+    #[inline]
+    pub fn back<'a>(&'a self, owner : &'a G::Owner) -> Option<&'a T> {
+        self.tail.as_ref().map(|tail| &owner.ro(tail).value)
+    }
+
+    pub fn iter<'a>(&'a self, owner : &'a G::Owner) -> impl Iterator<Item = &'a T> + 'a {
+        let mut current = self.head.as_deref();
+        std::iter::from_fn(move || {
+            let node = owner.ro(current?);
+            current = node.next.as_deref();
+            Some(&node.value)
+        })
+    }
+
+    /// Iterates over every value mutably, at the same time.
+    pub fn iter_mut<'a>(&'a self, owner : &'a mut G::Owner) -> impl Iterator<Item = &'a mut T> + 'a {
+        let mut current = self.head.as_deref();
+        std::iter::from_fn(move || {
+            let node : *mut ListNode<G, T> = owner.rw(current?);
+            // SAFETY: The owner is borrowed mutably for all of `'a`, each
+            // node is visited once, and `GroupOwner` requires that `rw` never
+            // aliases distinct cells, so the values which are handed out never
+            // alias. The nodes are kept alive by the list, which cannot
+            // change while `self` is borrowed.
+            let ListNode { value, next, .. } = unsafe { &mut *node };
+            current = next.as_deref();
+            Some(value)
+        })
+    }
+
+    /// Removes every value from the list.
+    pub fn clear(&mut self) {
+        self.tail = None;
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = Rc::try_unwrap(node).ok().and_then(|cell| G::Owner::into_inner(cell).next);
+        }
+        self.len = 0;
+    }
+
+    // Nodes are only shared with `Weak` pointers once they leave the list.
+    fn unwrap_node(node : Rc<ListCell<G, T>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(cell) => G::Owner::into_inner(cell).value,
+            Err(_) => unreachable!("a removed node is still linked")
+        }
+    }
+}
+
+impl<G, T> Default for CellList<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Long lists are dropped one node at a time, instead of recursively.
+impl<G, T> Drop for CellList<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+
+    def_cells! {
+        [mod] t_grp: TCellUniGrp;
+        [mod] tl_grp: TLCellUniGrp;
+    }
+
+    fn check_arena<G>(owner : &mut G::Owner)
+    where
+        G : CellGroup,
+        G::Owner : GroupOwner
+    {
+        let mut arena : CellArena<G, i32> = CellArena::new();
+        let root = arena.insert(0);
+        let a = arena.insert(1);
+        let b = arena.insert(2);
+        let c = arena.insert(3);
+        arena.append_child(owner, root, a);
+        arena.append_child(owner, root, b);
+        arena.append_child(owner, a, c);
+
+        assert_eq!(arena.children(owner, root).collect::<Vec<_>>(), [a, b]);
+        assert_eq!(arena.ancestors(owner, c).collect::<Vec<_>>(), [a, root]);
+        assert_eq!(arena.descendants(owner, root).collect::<Vec<_>>(), [root, a, c, b]);
+        assert_eq!(arena.descendants(owner, a).collect::<Vec<_>>(), [a, c]);
+
+        for id in arena.descendants(owner, root).collect::<Vec<_>>() {
+            *arena.get_mut(owner, id).unwrap() *= 10;
+        }
+        arena.append_child(owner, b, a);
+        assert_eq!(arena.children(owner, root).collect::<Vec<_>>(), [b]);
+        assert_eq!(arena.parent(owner, a), Some(b));
+        assert_eq!(arena.descendants(owner, root).map(|id| *arena.get(owner, id).unwrap()).collect::<Vec<_>>(), [0, 20, 10, 30]);
+
+        assert_eq!(arena.remove(owner, a), Some(10));
+        assert!(!arena.contains(a));
+        assert_eq!(arena.get(owner, a), None);
+        assert_eq!(arena.parent(owner, c), None);
+        assert_eq!(arena.children(owner, b).count(), 0);
+
+        let d = arena.insert(4);
+        assert_ne!(a, d);
+        assert_eq!(arena.len(), 4);
+        assert_eq!(arena.iter(owner).map(|(_, value)| value).sum::<i32>(), 54);
+    }
+
+    fn check_list<G>(owner : &mut G::Owner)
+    where
+        G : CellGroup,
+        G::Owner : GroupOwner
+    {
+        let mut list : CellList<G, i32> = CellList::new();
+        list.push_back(owner, 2);
+        list.push_front(owner, 1);
+        list.push_back(owner, 3);
+        assert_eq!(list.len(), 3);
+        assert_eq!((list.front(owner), list.back(owner)), (Some(&1), Some(&3)));
+
+        list.iter_mut(owner).for_each(|value| *value *= 2);
+        assert_eq!(list.iter(owner).copied().collect::<Vec<_>>(), [2, 4, 6]);
+        assert_eq!(list.pop_back(owner), Some(6));
+        assert_eq!(list.pop_front(owner), Some(2));
+        assert_eq!(list.pop_back(owner), Some(4));
+        assert_eq!(list.pop_front(owner), None);
+        assert!(list.is_empty() && list.back(owner).is_none());
+
+        for value in 0..100_000 {
+            list.push_back(owner, value);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn t_cell_graph_tests() {
+        let mut owner = t_grp::new_uniform_owner();
+        check_arena::<t_grp::UniMarker>(&mut owner);
+        check_list::<t_grp::UniMarker>(&mut owner);
+    }
+
+    #[test]
+    fn tl_cell_graph_tests() {
+        let mut owner = tl_grp::new_uniform_owner();
+        check_arena::<tl_grp::UniMarker>(&mut owner);
+        check_list::<tl_grp::UniMarker>(&mut owner);
+    }
+
+    #[test]
+    fn arena_retired_slot_test() {
+        let mut owner = t_grp::new_uniform_owner();
+        let mut arena : CellArena<t_grp::UniMarker, u8> = CellArena::new();
+        let first = arena.insert(1);
+        arena.slots[first.index].generation = u32::MAX;
+        let last = NodeId { generation : u32::MAX, ..first };
+
+        assert_eq!(arena.remove(&mut owner, last), Some(1));
+        assert!(arena.is_empty());
+        let next = arena.insert(2);
+        assert_ne!(next.index, last.index);
+        assert!(!arena.contains(last) && !arena.contains(first));
+        assert_eq!(arena.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "a node cannot be appended to itself or to its descendants")]
+    fn arena_cycle_test() {
+        let mut owner = tl_grp::new_uniform_owner();
+        let mut arena : CellArena<tl_grp::UniMarker, ()> = CellArena::new();
+        let parent = arena.insert(());
+        let child = arena.insert(());
+        arena.append_child(&mut owner, parent, child);
+        arena.append_child(&mut owner, child, parent);
+    }
+}
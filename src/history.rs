@@ -0,0 +1,467 @@
+//! Cells which remember their earlier contents, so that changes made
+//! through an owner can be undone and redone.
+//!
+//! A [`HistoryCell`] is kept in a cell of the group `G`, which is the
+//! marker of a [`TCell`] or [`TLCell`] group, such as `my_grp::UniMarker`.
+//! Every [`rw`] or [`replace`] through the group's owner saves the contents
+//! that the cell held before, and [`undo`] and [`redo`] step back and forth
+//! through them:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::history::HistoryCell;
+//!
+//! def_cells! {
+//!     [pub mod] editor: TCellUniGrp;
+//! }
+//!
+//! let mut owner = editor::new_uniform_owner();
+//! let title: HistoryCell<editor::UniMarker, String> = HistoryCell::new("draft".into());
+//!
+//! title.rw(&mut owner).push_str(" 2");
+//! title.replace(&mut owner, "final".into());
+//! assert!(title.undo(&mut owner));
+//! assert_eq!(title.ro(&owner), "draft 2");
+//! assert!(title.redo(&mut owner));
+//! assert_eq!(title.ro(&owner), "final");
+//! ```
+//!
+//! Cells which change together are made by a [`HistoryGroup`], which
+//! gathers every change between two [`checkpoint`]s into one step, and
+//! undoes or redoes that step across all of its cells at once. Making a
+//! fresh change discards the steps that could have been redone, and a
+//! limit can be given to cap how many steps each cell keeps.
+//!
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+//! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+//! [`rw`]: ./struct.HistoryCell.html#method.rw
+//! [`replace`]: ./struct.HistoryCell.html#method.replace
+//! [`undo`]: ./struct.HistoryCell.html#method.undo
+//! [`redo`]: ./struct.HistoryCell.html#method.redo
+//! [`checkpoint`]: ./struct.HistoryGroup.html#method.checkpoint
+
+use std::collections::VecDeque;
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::collections::CellOf;
+use crate::{CellGroup, GroupOwner};
+
+// Numbers the steps of a history. A standalone cell starts a new step on
+// every change, while a group only does so at its checkpoints. The branch
+// moves on with every change, which marks older redo steps as stale.
+struct Clock {
+    step : u64,
+    branch : u64,
+    auto_step : bool
+}
+
+struct Saved<T> {
+    step : u64,
+    value : T
+}
+
+struct Record<T> {
+    value : T,
+    undo : VecDeque<Saved<T>>,
+    redo : Vec<Saved<T>>,
+    redo_branch : u64,
+    limit : Option<usize>
+}
+
+/// A cell of the group `G`, which keeps the earlier contents that it held,
+/// so that changes to it can be undone.
+pub struct HistoryCell<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    record : CellOf<G, Record<T>>,
+    clock : Rc<CellOf<G, Clock>>
+}
+
+impl<G, T> HistoryCell<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates a `HistoryCell` with no limit on the number of changes that
+    /// it keeps.
+    #[inline]
+    pub fn new(value : T) -> Self {
+        Self::with_limit(value, None)
+    }
+
+    /// Creates a `HistoryCell` which keeps at most `limit` changes, when a
+    /// limit is given, and forgets the oldest ones first.
+    pub fn with_limit(value : T, limit : impl Into<Option<usize>>) -> Self {
+        let clock = Clock { step : 0, branch : 0, auto_step : true };
+        Self::with_clock(value, limit.into(), Rc::new(G::Owner::new_cell(clock)))
+    }
+
+    fn with_clock(value : T, limit : Option<usize>, clock : Rc<CellOf<G, Clock>>) -> Self {
+        let record = Record { value, undo : VecDeque::new(), redo : Vec::new(), redo_branch : 0, limit };
+        Self { record : G::Owner::new_cell(record), clock }
+    }
+
+    #[inline]
+    pub fn ro<'a>(&'a self, owner : &'a G::Owner) -> &'a T {
+        &owner.ro(&self.record).value
+    }
+
+    /// Saves the current contents as a change, and returns them mutably.
+    pub fn rw<'a>(&'a self, owner : &'a mut G::Owner) -> &'a mut T
+    where
+        T : Clone
+    {
+        if let Some(step) = self.begin_change(owner) {
+            let saved = owner.ro(&self.record).value.clone();
+            Self::save(owner.rw(&self.record), step, saved);
+        }
+        &mut owner.rw(&self.record).value
+    }
+
+    /// Saves the current contents as a change, and then replaces them with
+    /// `value`, returning the current contents.
+    pub fn replace(&self, owner : &mut G::Owner, value : T) -> T
+    where
+        T : Clone
+    {
+        mem::replace(self.rw(owner), value)
+    }
+
+    /// Restores the contents from before the latest change, and returns
+    /// whether there was a change to undo.
+    pub fn undo(&self, owner : &mut G::Owner) -> bool {
+        let branch = owner.ro(&*self.clock).branch;
+        let record = owner.rw(&self.record);
+        match record.undo.pop_back() {
+            Some(Saved { step, value }) => {
+                if record.redo_branch != branch {
+                    record.redo.clear();
+                    record.redo_branch = branch;
+                }
+                let newer = mem::replace(&mut record.value, value);
+                record.redo.push(Saved { step, value : newer });
+                true
+            },
+            None => false
+        }
+    }
+
+    /// Restores the contents from before the latest undo, and returns
+    /// whether there was an undo to redo. Undos can't be redone once a
+    /// fresh change has been made.
+    pub fn redo(&self, owner : &mut G::Owner) -> bool {
+        if self.next_redo(owner).is_none() {
+            return false;
+        }
+        let record = owner.rw(&self.record);
+        let Saved { step, value } = record.redo.pop().expect("a redo step was found");
+        let older = mem::replace(&mut record.value, value);
+        Self::save(record, step, older);
+        true
+    }
+
+    #[inline]
+    pub fn can_undo(&self, owner : &G::Owner) -> bool {
+        !owner.ro(&self.record).undo.is_empty()
+    }
+
+    #[inline]
+    pub fn can_redo(&self, owner : &G::Owner) -> bool {
+        self.next_redo(owner).is_some()
+    }
+
+    /// Forgets every change which could be undone or redone.
+    pub fn clear_history(&self, owner : &mut G::Owner) {
+        let record = owner.rw(&self.record);
+        record.undo.clear();
+        record.redo.clear();
+    }
+
+    /// Consumes the `HistoryCell`, and returns its contents.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        G::Owner::into_inner(self.record).value
+    }
+
+    // Moves the clock on for a change, and returns the step to save the
+    // change under, unless this step already saved an earlier change.
+    fn begin_change(&self, owner : &mut G::Owner) -> Option<u64> {
+        let clock = owner.rw(&*self.clock);
+        if clock.auto_step {
+            clock.step += 1;
+        }
+        clock.branch += 1;
+        let step = clock.step;
+        let record = owner.rw(&self.record);
+        record.redo.clear();
+        match record.undo.back() {
+            Some(saved) if saved.step == step => None,
+            _ => Some(step)
+        }
+    }
+
+    fn save(record : &mut Record<T>, step : u64, value : T) {
+        record.undo.push_back(Saved { step, value });
+        if let Some(limit) = record.limit {
+            while record.undo.len() > limit {
+                record.undo.pop_front();
+            }
+        }
+    }
+
+    fn last_undo(&self, owner : &G::Owner) -> Option<u64> {
+        owner.ro(&self.record).undo.back().map(|saved| saved.step)
+    }
+
+    fn next_redo(&self, owner : &G::Owner) -> Option<u64> {
+        let record = owner.ro(&self.record);
+        if record.redo_branch != owner.ro(&*self.clock).branch {
+            return None;
+        }
+        record.redo.last().map(|saved| saved.step)
+    }
+}
+
+// Lets a `HistoryGroup` step through cells with contents of any `type`.
+trait Rewind<O> {
+    fn last_undo(&self, owner : &O) -> Option<u64>;
+    fn next_redo(&self, owner : &O) -> Option<u64>;
+    fn undo(&self, owner : &mut O) -> bool;
+    fn redo(&self, owner : &mut O) -> bool;
+}
+
+impl<G, T> Rewind<G::Owner> for HistoryCell<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn last_undo(&self, owner : &G::Owner) -> Option<u64> {
+        HistoryCell::last_undo(self, owner)
+    }
+
+    #[inline]
+    fn next_redo(&self, owner : &G::Owner) -> Option<u64> {
+        HistoryCell::next_redo(self, owner)
+    }
+
+    #[inline]
+    fn undo(&self, owner : &mut G::Owner) -> bool {
+        HistoryCell::undo(self, owner)
+    }
+
+    #[inline]
+    fn redo(&self, owner : &mut G::Owner) -> bool {
+        HistoryCell::redo(self, owner)
+    }
+}
+
+/// Makes [`HistoryCell`]s which share their steps, so that the changes
+/// made to any of them between two checkpoints are undone and redone
+/// together.
+///
+/// ```rust
+/// # use cell_wrappers::*;
+/// use cell_wrappers::history::HistoryGroup;
+///
+/// def_cells! {
+///     [pub mod] editor: TCellUniGrp;
+/// }
+///
+/// let mut owner = editor::new_uniform_owner();
+/// let mut history: HistoryGroup<editor::UniMarker> = HistoryGroup::new();
+/// let width = history.new_cell(640);
+/// let height = history.new_cell(480);
+///
+/// history.checkpoint(&mut owner);
+/// *width.rw(&mut owner) = 800;
+/// *height.rw(&mut owner) = 600;
+/// assert!(history.undo(&mut owner));
+/// assert_eq!((*width.ro(&owner), *height.ro(&owner)), (640, 480));
+/// ```
+///
+/// [`HistoryCell`]: ./struct.HistoryCell.html
+pub struct HistoryGroup<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    clock : Rc<CellOf<G, Clock>>,
+    cells : Vec<Weak<dyn Rewind<G::Owner>>>,
+    limit : Option<usize>
+}
+
+impl<G> HistoryGroup<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates a `HistoryGroup` with no limit on the number of changes that
+    /// each of its cells keeps.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_limit(None)
+    }
+
+    /// Creates a `HistoryGroup` whose cells each keep at most `limit`
+    /// changes, when a limit is given.
+    pub fn with_limit(limit : impl Into<Option<usize>>) -> Self {
+        let clock = Clock { step : 0, branch : 0, auto_step : false };
+        Self { clock : Rc::new(G::Owner::new_cell(clock)), cells : Vec::new(), limit : limit.into() }
+    }
+
+    /// Creates a [`HistoryCell`] which shares the steps of this group.
+    ///
+    /// [`HistoryCell`]: ./struct.HistoryCell.html
+    pub fn new_cell<T>(&mut self, value : T) -> Rc<HistoryCell<G, T>>
+    where
+        G : 'static,
+        T : 'static
+    {
+        let cell = Rc::new(HistoryCell::with_clock(value, self.limit, self.clock.clone()));
+        let rewind : Rc<dyn Rewind<G::Owner>> = cell.clone();
+        self.cells.push(Rc::downgrade(&rewind));
+        cell
+    }
+
+    /// Starts a new step, so that later changes are undone apart from
+    /// earlier ones.
+    #[inline]
+    pub fn checkpoint(&self, owner : &mut G::Owner) {
+        owner.rw(&*self.clock).step += 1;
+    }
+
+    /// Undoes the latest step, in every cell that changed during it, and
+    /// returns whether there was a step to undo.
+    pub fn undo(&mut self, owner : &mut G::Owner) -> bool {
+        let cells = self.live_cells();
+        match cells.iter().filter_map(|cell| cell.last_undo(owner)).max() {
+            Some(step) => {
+                let stepped : Vec<_> = cells.iter().filter(|cell| cell.last_undo(owner) == Some(step)).collect();
+                for cell in stepped {
+                    cell.undo(owner);
+                }
+                true
+            },
+            None => false
+        }
+    }
+
+    /// Redoes the latest undone step, in every cell that it was undone in,
+    /// and returns whether there was a step to redo.
+    pub fn redo(&mut self, owner : &mut G::Owner) -> bool {
+        let cells = self.live_cells();
+        match cells.iter().filter_map(|cell| cell.next_redo(owner)).min() {
+            Some(step) => {
+                let stepped : Vec<_> = cells.iter().filter(|cell| cell.next_redo(owner) == Some(step)).collect();
+                for cell in stepped {
+                    cell.redo(owner);
+                }
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn can_undo(&self, owner : &G::Owner) -> bool {
+        self.cells.iter()
+            .filter_map(Weak::upgrade)
+            .any(|cell| cell.last_undo(owner).is_some())
+    }
+
+    pub fn can_redo(&self, owner : &G::Owner) -> bool {
+        self.cells.iter()
+            .filter_map(Weak::upgrade)
+            .any(|cell| cell.next_redo(owner).is_some())
+    }
+
+    // Forgets the cells which have been dropped, and returns the others.
+    fn live_cells(&mut self) -> Vec<Rc<dyn Rewind<G::Owner>>> {
+        self.cells.retain(|cell| cell.strong_count() > 0);
+        self.cells.iter().filter_map(Weak::upgrade).collect()
+    }
+}
+
+impl<G> Default for HistoryGroup<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+
+    def_cells! {
+        [mod] edit_grp: TCellUniGrp;
+        [mod] doc_grp: TLCellPubGrp;
+    }
+
+    #[test]
+    fn history_cell_tests() {
+        let mut owner = edit_grp::new_uniform_owner();
+        let text: HistoryCell<edit_grp::UniMarker, String> = HistoryCell::with_limit(String::new(), 2);
+        assert!(!text.undo(&mut owner));
+
+        for word in ["a", "b", "c"] {
+            text.rw(&mut owner).push_str(word);
+        }
+        assert!(text.undo(&mut owner));
+        assert!(text.undo(&mut owner));
+        assert!(!text.undo(&mut owner));
+        assert_eq!(text.ro(&owner), "a");
+
+        assert!(text.redo(&mut owner));
+        assert_eq!(text.replace(&mut owner, "z".into()), "ab");
+        assert!(!text.can_redo(&owner));
+        assert!(text.undo(&mut owner));
+        assert_eq!(text.ro(&owner), "ab");
+
+        text.clear_history(&mut owner);
+        assert!(!text.can_undo(&owner) && !text.can_redo(&owner));
+        assert_eq!(text.into_inner(), "ab");
+    }
+
+    #[test]
+    fn history_group_tests() {
+        let mut owner = doc_grp::new_public_owner();
+        let mut history: HistoryGroup<doc_grp::PubMarker> = HistoryGroup::new();
+        let (x, y) = (history.new_cell(0), history.new_cell('a'));
+
+        *x.rw(&mut owner) = 1;
+        *x.rw(&mut owner) = 2;
+        history.checkpoint(&mut owner);
+        *y.rw(&mut owner) = 'b';
+        history.checkpoint(&mut owner);
+        *x.rw(&mut owner) = 3;
+        *y.rw(&mut owner) = 'c';
+
+        assert!(history.undo(&mut owner));
+        assert_eq!((*x.ro(&owner), *y.ro(&owner)), (2, 'b'));
+        assert!(history.undo(&mut owner));
+        assert_eq!((*x.ro(&owner), *y.ro(&owner)), (2, 'a'));
+        assert!(history.redo(&mut owner));
+        assert_eq!((*x.ro(&owner), *y.ro(&owner)), (2, 'b'));
+
+        assert!(y.undo(&mut owner));
+        assert!(history.undo(&mut owner));
+        assert_eq!((*x.ro(&owner), *y.ro(&owner)), (0, 'a'));
+        assert!(!history.can_undo(&owner));
+
+        history.checkpoint(&mut owner);
+        *x.rw(&mut owner) = 5;
+        assert!(!history.can_redo(&owner));
+        drop(x);
+        assert!(!history.undo(&mut owner));
+        assert_eq!(history.cells.len(), 1);
+    }
+}
@@ -54,6 +54,19 @@
 //! }
 //! ```
 //! 
+//! Nested entries are `pub mod` unless given their own visibility,
+//! and can carry their own attributes, such as `cfg` gates:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! def_cells! {
+//!     [pub mod] app::{
+//!         #[cfg(feature = "std")] [pub(crate) mod] net: TCellPubGrp,
+//!         ui: TLCellUniGrp
+//!     };
+//! }
+//! ```
+//! 
 //! The following `type`s were also declared in `put_some_here::a_bit_further`,
 //! for example, since we declared it as a `TLCellUniGrp`:
 //! 
@@ -62,8 +75,8 @@
 //!     pub mod a_bit_further {
 //!         // This is a TLCell Uni Grp (or "TLCellUniGrp"):
 //!         pub struct UniMarker;
-//!         pub type UniCell<T> = qcell::TLCell<UniMarker,T>;
-//!         pub type UniOwner = qcell::TLCellOwner<UniMarker>;
+//!         pub type UniCell<T> = cell_wrappers::qcell::TLCell<UniMarker,T>;
+//!         pub type UniOwner = cell_wrappers::qcell::TLCellOwner<UniMarker>;
 //! 
 //!         // Utility traits are implemented too:
 //!         // ...
@@ -71,6 +84,139 @@
 //! }
 //! ```
 //! 
+//! Groups can also be declared as families of markers, by giving
+//! them `const` or `type` parameters:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! def_cells! {
+//!     [pub mod] shard: TCellUniGrp<const ID: usize>;
+//! }
+//! ```
+//! 
+//! Each instantiation, such as `shard::UniOwner<3>`, is a distinct
+//! owner type with its own `shard::UniCell<3, T>` cells.
+//! 
+//! The items of a group can also be given custom names:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! def_cells! {
+//!     [pub mod] audio: TCellAccGrp as {
+//!         pub: (MixerMarker, MixerOwner, MixerCell),
+//!         pvt: (DeviceMarker, DeviceOwner, DeviceCell)
+//!     };
+//! }
+//! ```
+//! 
+//! And groups can select options with a `with` clause, such as
+//! `cells: newtype`, which declares each cell as a wrapper `struct`
+//! instead of a `type` alias, so that `trait`s can be implemented on it:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! def_cells! {
+//!     [pub mod] settings: TCellUniGrp with { cells: newtype };
+//! }
+//! ```
+//! 
+//! Owners can be declared as wrappers too, with `owners: newtype`.
+//! These know the path of their group, and run the `on_acquire` and
+//! `on_release` hooks of an `OwnerHooks` type whenever they are
+//! created or dropped, which is given with `hooks: (path::ToHooks)`.
+//! 
+//! Every group also declares shared aliases of its cells, such as
+//! `RcUniCell<T>`, `ArcPubCell<T>` and `WeakUniCell<T>`, with
+//! `new_rc_uniform_cell` and `new_arc_public_cell` style constructors,
+//! when the `alloc` feature is enabled.
+//! 
+//! Many sibling cells of one group can be kept in the
+//! `collections::CellVec` and `collections::CellMap` types, which are
+//! keyed to the marker of the group, and let a single owner read or
+//! mutate every element in one pass:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::collections::CellVec;
+//! # def_cells! {
+//! #     [pub mod] scores: TCellUniGrp;
+//! # }
+//! 
+//! let mut owner = scores::new_uniform_owner();
+//! let list: CellVec<scores::UniMarker, u32> = vec![1, 2].into();
+//! for score in list.iter_mut(&mut owner) {
+//!     *score *= 10;
+//! }
+//! ```
+//! 
+//! Linked structures can be built with the `graph` module, which
+//! offers a `CellArena` of parent and child linked nodes, with stable
+//! `NodeId` handles, and a doubly-linked `CellList`. Both keep their
+//! links in cells of one group, so they are rewired and traversed
+//! through the group's owner.
+//! 
+//! Cells can be printed with an owner, through the `debug_with` and
+//! `display_with` adapters of the `cell_fmt` module, or several at a
+//! time with `fmt_cells!`, while `dbg_cell!` works like `dbg!`:
+//! 
+//! ```rust
+//! # use cell_wrappers::*;
+//! # def_cells! {
+//! #     [pub mod] scores: TCellUniGrp;
+//! # }
+//! let owner = scores::new_uniform_owner();
+//! let (hits, misses) = (scores::new_uniform_cell(9), scores::new_uniform_cell(1));
+//! println!("{}", fmt_cells!(owner; hits, misses));
+//! ```
+//! 
+//! Cells of one group can likewise be compared and hashed by their
+//! contents under a single owner, with the `eq_with`, `cmp_with` and
+//! `hash_with` methods of the `cell_cmp` module. Its `Keyed` adapter
+//! pairs a cell with a borrowed owner, so that cells can be sorted or
+//! kept in a `HashSet` by what they hold.
+//! 
+//! Updates which span several cells can be made with `c_txn!`, which
+//! takes an owner like `c_scp!` does, followed by the cells to snapshot,
+//! each with an optional `c_scp!` target to bind. If its body returns an
+//! `Err`, or panics, then every listed cell is restored before the owner
+//! is released, so no half-applied changes are left behind.
+//! 
+//! Undo and redo come from the `history` module, whose `HistoryCell`
+//! saves its earlier contents on every `rw` or `replace` through the
+//! owner, up to an optional limit. A `HistoryGroup` makes cells which
+//! share checkpoints, so that a step spanning several of them is undone
+//! or redone as one.
+//! 
+//! Derived data can be kept up to date with the `tracked` module, whose
+//! `TrackedCell` takes a new version and a dirty mark whenever it is
+//! borrowed mutably, including by the `mut` forms of `c_scp!`. Cells made
+//! by one `Tracker` share a version counter, so it can tell whether any
+//! of them changed since a version that was seen earlier.
+//! 
+//! The `reactive` module builds a small reactive layer on a group, from
+//! the `Signal`s and `Derived` values of a `Reactor`. Derived values are
+//! computed lazily from what they read, and their subscribers are called
+//! once a write scope ends, so they never see half-applied updates.
+//! 
+//! Update loops can hand owners to their systems through the `Scheduler`
+//! of the `scheduler` module. Each system claims the groups that it reads
+//! or writes, is checked for conflicting claims when it is registered, and
+//! is run with exactly those owners. Systems which only read can share
+//! their owners across scoped threads, so the scheduler only holds owners
+//! which are `Send` and `Sync`, such as those of `TCell` groups, and not
+//! those of `TLCell` groups.
+//! 
+//! Components which should only read a group can be given a `ReadOwner`
+//! from the `capability` module, instead of the owner itself. It borrows
+//! an owner, can be copied and stored, and works with `ro` and the
+//! immutable forms of `c_scp!`, while the `mut` forms reject it when
+//! compiling.
+//! 
+//! Groups which are only written during startup can then be frozen, with
+//! `capability::freeze`, which consumes their owner and returns a
+//! `FrozenOwner`. It reads the group from any thread, for the rest of the
+//! process, and keeps any new owner of a `TCell` group from being made.
+//! 
 //! # Cell system subcategories
 //! 
 //! This crate also offers four subcategories of cell systems, which offer
@@ -128,6 +274,16 @@
 //!     pub owner: BarOwner,
 //!     pub cell: BarCell<T>
 //! }
+//! 
+//! // Groups are uniform by default, but can be made private or public:
+//! new_t_group!(pvt QuxOwner[QuxMarker] => QuxCell<T>);
+//! 
+//! new_tl_group! {
+//!     access: public,
+//!     marker: QuuxMarker,
+//!     owner: QuuxOwner,
+//!     cell: QuuxCell<T>
+//! }
 //! ```
 //! 
 //! Read more at:
@@ -135,6 +291,7 @@
 //! * [`new_t_marker`] / [`new_tl_marker`]
 //! * [`new_t_owner`] / [`new_tl_owner`]
 //! * [`new_t_cell`] / [`new_tl_cell`]
+//! * [`new_group`], which selects the implementation by keyword
 //! 
 //! # Quick owner scopes
 //! 
@@ -178,6 +335,11 @@
 //! 4. `use &borrowed_owner =>` or `use &mut borrowed_owner =>` \
 //! This selects an owner available in the surrounding scope,
 //! declared with the identifier of `borrowed_owner`, for example.
+//! 5. `use <G as CellGroup>::Owner =>` \
+//!    Any owner type can be named, including generic and qualified
+//!    types, such as `shard::UniOwner<3>` or `crate::grp::UniOwner`.
+//! 6. `use (make_owner()) =>` or `let owner_name = (make_owner()) =>` \
+//!    This builds the owner with the expression in parentheses.
 //! 
 //! ## Cell and container references
 //! 
@@ -198,6 +360,10 @@
 //! 2. > `(mut self.test_cell)` \
 //! ...which coerces the owner to be borrowing ***mutably***.
 //! 
+//! A `Weak` pointer to a cell can be selected with `weak`, as in
+//! `(weak self.parent => mut parent_cont)`. It is upgraded first,
+//! and the scope is skipped if the cell has already been dropped.
+//! 
 //! ### Container reference options
 //! 
 //! There are quite a lot of ways to declare the container variable:
@@ -284,6 +450,44 @@
 //! * [`GetPubOwner`] / [`impl_get_pub`]
 //! * [`GetUniOwner`] / [`impl_get_uni`]
 //! 
+//! # Cargo features
+//! 
+//! This crate is `#![no_std]` unless the `std` feature is enabled,
+//! and its features mirror the ones found in [`qcell`]:
+//! 
+//! 1. `std` (default): Enables the `TCell...Grp` and `TLCell...Grp`
+//!    group types, along with the families-style macros.
+//!    Also enables `alloc`.
+//! 2. `alloc`: Enables the `QCell...Grp` group types.
+//! 3. `serde`: Enables the `cell_serde` module, which serializes cells
+//!    under an owner, with `cell.serialize_with(&owner)`, or with
+//!    `#[serde(with = "cell_wrappers::cell_serde")]` fields inside of a
+//!    `WithOwner`, and deserializes them into fresh cells.
+//!    Also enables `std`.
+//! 
+//! `new_group!(impl = auto, ...)` is not switched by a feature of this
+//! crate, since Cargo would then switch it for every crate in the build.
+//! Instead, it declares [`TLCell`] groups when the calling crate's own
+//! `auto-tl` feature is enabled, and [`TCell`] groups otherwise, so a crate
+//! which uses it should declare that feature:
+//! 
+//! ```toml
+//! [features]
+//! auto-tl = []
+//! ```
+//! 
+//! The `LCell...Grp` group types and [`c_scp`] are always available,
+//! so an embedded project can still organize its cells with [`def_cells`]:
+//! 
+//! ```toml
+//! [dependencies]
+//! cell_wrappers = { version = "0.1", default-features = false, features = ["alloc"] }
+//! ```
+//! 
+//! Every macro refers to [`qcell`] through the `cell_wrappers::qcell`
+//! re-export, so a crate using these macros does not need its own
+//! `qcell` dependency.
+//! 
 //! [`cell_wrappers`]: ./index.html
 //! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
 //! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
@@ -292,6 +496,7 @@
 //! [`def_cells`]: ./macro.def_cells.html
 //! [`new_t_group`]: ./macro.new_t_group.html
 //! [`new_tl_group`]: ./macro.new_tl_group.html
+//! [`new_group`]: ./macro.new_group.html
 //! [`new_t_marker`]: ./macro.new_t_marker.html
 //! [`new_tl_marker`]: ./macro.new_tl_marker.html
 //! [`new_t_owner`]: ./macro.new_t_owner.html
@@ -306,6 +511,46 @@
 //! [`impl_get_pub`]: ./macro.impl_get_pub.html
 //! [`impl_get_uni`]: ./macro.impl_get_uni.html
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+/// The [`qcell`] crate, re-exported so that the macros of this crate
+/// can be used without depending on [`qcell`] directly.
+/// 
+/// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
+pub use qcell;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod collections;
+#[cfg(feature = "std")]
+pub mod graph;
+#[cfg(feature = "std")]
+pub mod history;
+#[cfg(feature = "std")]
+pub mod tracked;
+#[cfg(feature = "std")]
+pub mod reactive;
+#[cfg(feature = "std")]
+pub mod scheduler;
+#[cfg(feature = "serde")]
+pub mod cell_serde;
+pub mod cell_fmt;
+pub mod cell_cmp;
+pub mod capability;
+#[cfg(feature = "std")]
+pub mod cell_txn;
+
+// Shared pointer types, for the `Rc`, `Arc` and `Weak` cell aliases
+// declared by `def_cells!`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __shared {
+    pub use alloc::rc::{Rc, Weak};
+    pub use alloc::sync::Arc;
+}
+
 /// This macro provides the ability to quickly and easily establish
 /// temporary scopes for operations involving a [`TCell`] / [`TLCell`],
 /// and its [`TCellOwner`] / [`TLCellOwner`].
@@ -331,15 +576,21 @@
 /// `B` specifies the owner type, and can be declared in a number of ways:
 /// 
 /// 1. > `path::to::OwnerType` \
-/// The path to the owner's type definition.
-/// This can be in the current module, or in an inline module created by [`def_cells`].
+/// The owner's type, which is created with its `new()` function.
+/// Any type is accepted, such as `crate::grp::UniOwner`, `shard::UniOwner<3>`,
+/// or `<G as CellGroup>::Owner`, as long as it names an owner in scope.
 /// 
-/// 2. > `_` \
+/// 2. > `(owner_expression)` \
+/// An expression in parentheses, which builds the owner.
+/// This is useful for owners returned by functions, such as
+/// `(G::new_owner())` in code that is generic over a [`CellGroup`].
+/// 
+/// 3. > `_` \
 /// Simply an underscore character, which will
 /// cause the macro to check the type of cell (specified by `C`), and create a
 /// new owner from the cell's system.
 /// 
-/// 3. > `[self]` \
+/// 4. > `[self]` \
 /// the `self` keyword, and ***must*** be contained within
 /// square brackets to be recognized, and also must be used in a `struct` method.
 /// This causes the macro to check the type of cell (specified by `C`),
@@ -356,7 +607,7 @@
 /// You may want to provide a custom implementation if you need extra instructions
 /// to be followed before returning a type of owner.
 /// 
-/// 4. > `& owner_identifier` \
+/// 5. > `& owner_identifier` \
 /// A borrow reference to an owner already found in scope.
 /// This is particularly useful if you are passing a reused owner into a `c_scp`
 /// call. You must also specify the reference as mutable, where applicable.
@@ -376,6 +627,12 @@
 /// * [`GetEasyPubOwner`]
 /// * [`GetEasyUniOwner`]
 /// 
+/// `C` can also be written as `weak my_weak_cell`, for a `Weak` pointer
+/// to a cell, such as a group's `WeakUniCell<T>`. The pointer is upgraded
+/// before the scope begins, and the scope is skipped if the cell has
+/// already been dropped. This form requires a `D`, since the upgraded
+/// pointer is not named.
+/// 
 /// # `D`
 /// 
 /// `D` is optional, and specifies a new variable to be created, which will store
@@ -433,6 +690,7 @@
 /// [`GetEasyPvtOwner`]: ./trait.GetEasyPvtOwner.html
 /// [`GetEasyPubOwner`]: ./trait.GetEasyPubOwner.html
 /// [`GetEasyUniOwner`]: ./trait.GetEasyUniOwner.html
+/// [`CellGroup`]: ./trait.CellGroup.html
 #[macro_export]
 macro_rules! c_scp {
     {
@@ -446,22 +704,36 @@ macro_rules! c_scp {
         & mut $decl
     };
     {
-        @handle_owner_source ( @from_path $type_path:path => ) ,
+        @handle_owner_source ( @from_type $owner_type:ty => ) ,
+        ( $scope:ident $ref_type:ident $muta:ident $cell_info:tt )
+    } => {
+        <$owner_type> :: new()
+    };
+    {
+        @handle_owner_source ( @from_expr $owner_expr:expr => ) ,
         ( $scope:ident $ref_type:ident $muta:ident $cell_info:tt )
     } => {
-        <$type_path> :: new()
+        $owner_expr
     };
     {
         @handle_owner_source ( @from_auto => ) ,
         ( $scope:ident $ref_type:ident $muta:ident ( $cell_src:expr ) )
     } => {
-        $cell_src . get_new_matching_owner()
+        {
+            #[allow(unused_imports)]
+            use $crate::{GetEasyPvtOwner as _, GetEasyPubOwner as _, GetEasyUniOwner as _};
+            $cell_src . get_new_matching_owner()
+        }
     };
     {
         @handle_owner_source ( @from_self $_self:ident => ) ,
         ( $scope:ident $ref_type:ident $muta:ident ( $cell_src:expr ) )
     } => {
-        $cell_src . get_matching_owner_from($_self)
+        {
+            #[allow(unused_imports)]
+            use $crate::{GetEasyPvtOwner as _, GetEasyPubOwner as _, GetEasyUniOwner as _};
+            $cell_src . get_matching_owner_from($_self)
+        }
     };
     ( @handle_sources_right $owner_name:ident , ( @from_scp_src_immut $owner_src:expr => ) , $op_props:tt ) => {
         let $owner_name = & $owner_src;
@@ -716,21 +988,47 @@ macro_rules! c_scp {
         $( : $container_type0 )? =
         $cell_src . ro ( $owner_name );
     };
+    // Scope builder
+    // Upgrade weak cell sources, skipping the scope if the cell was dropped
+    {
+        @scope (
+            $owner_type_header:tt ,
+            $owner_name:tt ,
+            (
+                weak $cell_src:expr => $( $target:tt )+
+            )
+        ) $( $statements:block )?
+    } => {
+        if let ::core::option::Option::Some(__upgraded_cell) = ( $cell_src ).upgrade() {
+            $crate::c_scp! {
+                @scope (
+                    $owner_type_header ,
+                    $owner_name ,
+                    ( __upgraded_cell => $( $target )+ )
+                ) $( $statements )?
+            }
+        }
+    };
+    {
+        @scope $details:tt $( $statements:block )?
+    } => {
+        {
+            $crate::c_scp! { @reorganize_body1 $details }
+            ; $( $statements )?
+        }
+    };
     // General pattern reorganizer
     {
         let $scope_owner_name:ident = _ =>
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_auto => ) ,
-                    ( $scope_owner_name ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_auto => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -738,15 +1036,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_auto => ) ,
-                    ( __scope_owner ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_auto => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -754,15 +1049,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_scp_src_ismut $owner_src => ) ,
-                    ( $scope_owner_name ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_scp_src_ismut $owner_src => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -770,15 +1062,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_scp_src_ismut $owner_src => ) ,
-                    ( __scope_owner ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_scp_src_ismut $owner_src => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -786,15 +1075,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_scp_src_immut $owner_src => ) ,
-                    ( $scope_owner_name ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_scp_src_immut $owner_src => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -802,15 +1088,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_scp_src_immut $owner_src => ) ,
-                    ( __scope_owner ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_scp_src_immut $owner_src => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -818,15 +1101,12 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_self $_self => ) ,
-                    ( $scope_owner_name ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_self $_self => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
@@ -834,152 +1114,537 @@ macro_rules! c_scp {
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_self $_self => ) ,
-                    ( __scope_owner ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_self $_self => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
+        }
+    };
+    {
+        let $scope_owner_name:ident = ( $owner_expr:expr ) =>
+        $operation_details:tt
+        $( $statements:block )? $(;)?
+    } => {
+        $crate::c_scp! {
+            @scope (
+                ( @from_expr $owner_expr => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
+        }
+    };
+    {
+        use ( $owner_expr:expr ) =>
+        $operation_details:tt
+        $( $statements:block )? $(;)?
+    } => {
+        $crate::c_scp! {
+            @scope (
+                ( @from_expr $owner_expr => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
         let $scope_owner_name:ident =
-        $owner_type_header:ident $( :: $owner_path_ext:ident )* =>
+        $owner_type:ty =>
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_path $owner_type_header $( :: $owner_path_ext )* => ) ,
-                    ( $scope_owner_name ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_type $owner_type => ) ,
+                ( $scope_owner_name ) ,
+                $operation_details
+            ) $( $statements )?
         }
     };
     {
         use
-        $owner_type_header:ident $( :: $owner_path_ext:ident )* =>
+        $owner_type:ty =>
         $operation_details:tt
         $( $statements:block )? $(;)?
     } => {
-        {
-            $crate::c_scp! {
-                @reorganize_body1 (
-                    ( @from_path $owner_type_header $( :: $owner_path_ext )* => ) ,
-                    ( __scope_owner ) ,
-                    $operation_details
-                )
-            }
-            ; $( $statements )?
+        $crate::c_scp! {
+            @scope (
+                ( @from_type $owner_type => ) ,
+                ( __scope_owner ) ,
+                $operation_details
+            ) $( $statements )?
         }
     }
 }
 
-/// This module contains empty structs which match the types of cell groups
-/// accepted by [`def_cells`], for those who need something for their linters to
-/// validate against.
+/// Formats several cells at once, with a shared owner.
 /// 
-/// [`def_cells`]: ./macro.def_cells.html
-mod cell_grp_types {
-    /// Represents a uniform group of [`TCell`]s for linters.
-    /// 
-    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-    #[allow(dead_code)]
-    pub struct TCellUniGrp;
-    /// Represents a uniform group of [`TLCell`]s for linters.
-    /// 
-    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-    #[allow(dead_code)]
-    pub struct TLCellUniGrp;
-    /// Represents an access group of [`TCell`]s for linters.
-    /// 
-    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-    #[allow(dead_code)]
-    pub struct TCellAccGrp;
-    /// Represents an access group of [`TLCell`]s for linters.
-    /// 
-    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-    #[allow(dead_code)]
-    pub struct TLCellAccGrp;
-    /// Represents a public group of [`TCell`]s for linters.
-    /// 
-    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-    #[allow(dead_code)]
-    pub struct TCellPubGrp;
-    /// Represents a public group of [`TLCell`]s for linters.
-    /// 
-    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-    #[allow(dead_code)]
-    pub struct TLCellPubGrp;
-    /// Represents a private group of [`TCell`]s for linters.
-    /// 
-    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-    #[allow(dead_code)]
-    pub struct TCellPvtGrp;
-    /// Represents a private group of [`TLCell`]s for linters.
-    /// 
-    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-    #[allow(dead_code)]
-    pub struct TLCellPvtGrp;
-}
-
-/// Represents the [`TCell`] and [`TLCell`] implementations.
-/// "GT" ("General-T") refers to the possibility of either T or TL,
-/// and is not represented here.
+/// The result implements both `Debug` and `Display`, and shows each cell
+/// as written in the macro, followed by its contents, much like a map:
 /// 
-/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub enum CellImpl {
-    /// Indicates the [`TCell`] implementation.
-    /// 
-    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-    T,
-    /// Indicates the [`TLCell`] implementation.
-    /// 
-    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-    TL
-}
-
-/// Represents the default access types for cell groups.
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub enum CellAccessLevels {
-    /// Uniform cell groups are general-purpose, with no implied
-    /// restrictions or organization schemes.
-    Uniform,
-    /// Private cell groups are implied to be used by same-`struct` methods.
-    Private,
-    /// Public cell groups are intended for program-wide accessibility.
-    Public
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] stats: TCellUniGrp;
+/// }
+/// 
+/// let owner = stats::new_uniform_owner();
+/// let hits = stats::new_uniform_cell(3);
+/// let misses = stats::new_uniform_cell(1);
+/// 
+/// let shown = format!("{}", fmt_cells!(owner; hits, misses));
+/// assert_eq!(shown, "{hits: 3, misses: 1}");
+/// ```
+/// 
+/// Every cell must belong to the group of the owner, and implement
+/// [`OwnedCell`], as the cells of this crate's groups do.
+/// 
+/// [`OwnedCell`]: ./trait.OwnedCell.html
+#[macro_export]
+macro_rules! fmt_cells {
+    ( $owner:expr ; $( $cell:expr ),+ $(,)? ) => {
+        $crate::cell_fmt::FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            #[allow(unused_imports)]
+            use $crate::cell_fmt::FmtWith as _;
+            f.debug_map()
+                $( .entry(
+                    &$crate::cell_fmt::Label(::core::stringify!($cell)),
+                    &( $cell ).debug_with(&$owner)
+                ) )+
+                .finish()
+        })
+    };
 }
 
-/// Represents the roles in the [`TCell`]/[`TLCell`] ecosystem.
+/// Prints cells and their contents to `stderr`, with a shared owner, and
+/// then returns the cells, in the same way as `dbg!`.
 /// 
-/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub enum CellRoles {
-    /// Markers restrict owner creation.
-    Marker,
-    /// Owners facilitate cell access.
-    Owner,
-    // Cells contain data.
-    Cell
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] stats: TCellUniGrp;
+/// }
+/// 
+/// let owner = stats::new_uniform_owner();
+/// let hits = stats::new_uniform_cell(3);
+/// 
+/// // Prints `[src/main.rs:10:13] &hits = 3`
+/// let hits_ref = dbg_cell!(owner; &hits);
+/// ```
+/// 
+/// Since the cells are returned, they are usually given by reference.
+/// This macro requires the `std` feature.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! dbg_cell {
+    ( $owner:expr ; $cell:expr $(,)? ) => {
+        match $cell {
+            cell => {
+                #[allow(unused_imports)]
+                use $crate::cell_fmt::FmtWith as _;
+                ::std::eprintln!(
+                    "[{}:{}:{}] {} = {:#?}",
+                    ::core::file!(),
+                    ::core::line!(),
+                    ::core::column!(),
+                    ::core::stringify!($cell),
+                    cell.debug_with(&$owner)
+                );
+                cell
+            }
+        }
+    };
+    ( $owner:expr ; $( $cell:expr ),+ $(,)? ) => {
+        ( $( $crate::dbg_cell!($owner; $cell) ),+ , )
+    };
 }
 
-// These are here for execution management and organization.
-
-/// This `trait` allows for standardized polling of implementation type,
-/// and indicates the [`TCell`] implementation.
+/// This macro opens a transactional scope, which works like [`c_scp`],
+/// but restores its cells if the scope fails.
 /// 
-/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-pub trait IsTImpl {
+/// The owner is given in the same ways as with [`c_scp`], and is followed
+/// by a list of the cells which take part in the transaction. Each cell
+/// may be followed by any of the targets that [`c_scp`] accepts, which are
+/// bound in order before the body runs:
+/// ```text
+/// let A = B => ( C, D => E, ... ) { ... }
+/// use B => ( C, D => E, ... ) { ... }
+/// ```
+/// 
+/// Each cell expression is evaluated once, when the scope begins. The
+/// contents of every listed cell are cloned at that point, so they must
+/// implement `Clone`. The body must evaluate to a `Result`,
+/// which becomes the result of the macro. If the body returns an `Err`, or
+/// panics, then every listed cell is given back the contents that it held
+/// at the start, before the owner is released, and the panic resumes.
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] game: TCellUniGrp;
+/// }
+/// 
+/// let mut owner = game::new_uniform_owner();
+/// let hp = game::new_uniform_cell(10);
+/// let gold = game::new_uniform_cell(3u32);
+/// 
+/// let result: Result<(), &str> = c_txn! {
+///     let txn_owner = &mut owner => (hp, gold => mut gold) {
+///         *gold = gold.checked_sub(2).ok_or("not enough gold")?;
+///         *hp.rw(txn_owner) += 5;
+///         if *hp.ro(txn_owner) > 12 {
+///             return Err("too much hp");
+///         }
+///         Ok(())
+///     }
+/// };
+/// 
+/// assert_eq!(result, Err("too much hp"));
+/// assert_eq!((*hp.ro(&owner), *gold.ro(&owner)), (10, 3));
+/// ```
+/// 
+/// The body is run as a closure, so `return` and `?` leave the transaction,
+/// rather than the enclosing function. Like the targets of nested
+/// [`c_scp`] scopes, only one target at a time can borrow the owner
+/// mutably. Immutably borrowed owners are rejected, since the cells could
+/// not be restored through them:
+/// 
+/// ```rust,compile_fail
+/// # use cell_wrappers::*;
+/// # def_cells! {
+/// #     [pub mod] game: TCellUniGrp;
+/// # }
+/// let owner = game::new_uniform_owner();
+/// let hp = game::new_uniform_cell(10);
+/// let result: Result<i32, ()> = c_txn! {
+///     use &owner => (hp => *hp) {
+///         Ok(hp)
+///     }
+/// };
+/// ```
+/// 
+/// This macro requires the `std` feature.
+/// 
+/// [`c_scp`]: ./macro.c_scp.html
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! c_txn {
+    // Cell list parser
+    // Each entry becomes `[ ( cell ) ( target ) ]`, where the target may
+    // be empty
+    {
+        @parse $head:tt $statements:block [ $( $done:tt )* ] ( )
+    } => {
+        $crate::c_txn! { @bind_cells $head $statements [ $( $done )* ] [ ] }
+    };
+    {
+        @parse $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr => $( $rest:tt )* )
+    } => {
+        $crate::c_txn! { @target $head $statements [ $( $done )* ] ( $cell_src ) [ ] ( $( $rest )* ) }
+    };
+    {
+        @parse $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr $( , $( $rest:tt )* )? )
+    } => {
+        $crate::c_txn! { @parse $head $statements [ $( $done )* [ ( $cell_src ) ( ) ] ] ( $( $( $rest )* )? ) }
+    };
+    {
+        @target $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr ) [ $( $target:tt )* ]
+        ( $( , $( $rest:tt )* )? )
+    } => {
+        $crate::c_txn! {
+            @parse $head $statements [ $( $done )* [ ( $cell_src ) ( $( $target )* ) ] ] ( $( $( $rest )* )? )
+        }
+    };
+    {
+        @target $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr ) [ $( $target:tt )* ]
+        ( : $target_type:ty $( , $( $rest:tt )* )? )
+    } => {
+        $crate::c_txn! {
+            @parse $head $statements
+            [ $( $done )* [ ( $cell_src ) ( $( $target )* : $target_type ) ] ] ( $( $( $rest )* )? )
+        }
+    };
+    {
+        @target $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr ) [ $( $target:tt )* ]
+        ( as $target_type:ty $( , $( $rest:tt )* )? )
+    } => {
+        $crate::c_txn! {
+            @parse $head $statements
+            [ $( $done )* [ ( $cell_src ) ( $( $target )* as $target_type ) ] ] ( $( $( $rest )* )? )
+        }
+    };
+    {
+        @target $head:tt $statements:block [ $( $done:tt )* ] ( $cell_src:expr ) [ $( $target:tt )* ]
+        ( $next:tt $( $rest:tt )* )
+    } => {
+        $crate::c_txn! {
+            @target $head $statements [ $( $done )* ] ( $cell_src ) [ $( $target )* $next ] ( $( $rest )* )
+        }
+    };
+    // Evaluates each cell expression once, and refers to the cell through
+    // its binding from then on. Each binding comes from its own expansion,
+    // so they do not shadow each other
+    {
+        @bind_cells $head:tt $statements:block [ ] [ $( $bound:tt )* ]
+    } => {
+        $crate::c_txn! { @dispatch $head $statements [ $( $bound )* ] }
+    };
+    {
+        @bind_cells $head:tt $statements:block
+        [ [ ( $cell_src:expr ) $target:tt ] $( $cell:tt )* ] [ $( $bound:tt )* ]
+    } => {
+        {
+            let __txn_cell = & $cell_src;
+            $crate::c_txn! {
+                @bind_cells $head $statements [ $( $cell )* ] [ $( $bound )* [ ( __txn_cell ) $target ] ]
+            }
+        }
+    };
+    {
+        @dispatch ( run $owner:tt ) $statements:block $cells:tt
+    } => {
+        $crate::c_txn! { @run $owner $cells $statements }
+    };
+    {
+        @dispatch ( bind $owner_type_header:tt $owner_name:ident ) $statements:block
+        [ [ ( $first_cell:expr ) $first_target:tt ] $( $cell:tt )* ]
+    } => {
+        {
+            $crate::c_scp!(
+                @handle_sources_right
+                $owner_name ,
+                $owner_type_header ,
+                ( internal borrow ismut ( $first_cell ) )
+            );
+            $crate::c_txn! {
+                @run ( * $owner_name ) [ [ ( $first_cell ) $first_target ] $( $cell )* ] $statements
+            }
+        }
+    };
+    // Binds the target of one cell, as `c_scp!` would
+    {
+        @bind_target $owner:tt ( $cell_src:expr ) ( )
+    } => {};
+    {
+        @bind_target $owner:tt ( $cell_src:expr ) ( $( $target:tt )+ )
+    } => {
+        $crate::c_scp! {
+            @reorganize_body1 (
+                ( @from_expr $owner => ) ,
+                ( __txn_target_owner ) ,
+                ( $cell_src => $( $target )+ )
+            )
+        }
+    };
+    {
+        @run $owner:tt [ $( [ ( $cell_src:expr ) $target:tt ] )+ ] $statements:block
+    } => {
+        {
+            let __txn_rollback = [ $(
+                {
+                    #[allow(unused_imports)]
+                    use $crate::cell_txn::SnapshotCell as _;
+                    ( $cell_src ) . snapshot ( & $owner )
+                }
+            ),+ ];
+            let __txn_result = ::std::panic::catch_unwind( ::core::panic::AssertUnwindSafe( || {
+                $( $crate::c_txn! { @bind_target $owner ( $cell_src ) $target } )+
+                $statements
+            } ) );
+            match __txn_result {
+                ::core::result::Result::Ok(result) => {
+                    if ::core::result::Result::is_err(&result) {
+                        for restore in __txn_rollback {
+                            restore( &mut $owner );
+                        }
+                    }
+                    result
+                }
+                ::core::result::Result::Err(payload) => {
+                    for restore in __txn_rollback {
+                        restore( &mut $owner );
+                    }
+                    ::std::panic::resume_unwind(payload)
+                }
+            }
+        }
+    };
+    // General pattern reorganizer
+    {
+        let $txn_owner_name:ident = & mut $owner_src:expr => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! {
+            @parse ( bind ( @from_scp_src_ismut $owner_src => ) $txn_owner_name ) $statements [ ] $cells
+        }
+    };
+    {
+        use & mut $owner_src:expr => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( run ( $owner_src ) ) $statements [ ] $cells }
+    };
+    {
+        let $txn_owner_name:ident = & $( $rest:tt )*
+    } => {
+        ::core::compile_error!("`c_txn!` needs a mutable owner, since it restores cells through it");
+    };
+    {
+        use & $( $rest:tt )*
+    } => {
+        ::core::compile_error!("`c_txn!` needs a mutable owner, since it restores cells through it");
+    };
+    {
+        let $txn_owner_name:ident = _ => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_auto => ) $txn_owner_name ) $statements [ ] $cells }
+    };
+    {
+        use _ => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_auto => ) __txn_owner ) $statements [ ] $cells }
+    };
+    {
+        let $txn_owner_name:ident = [$_self:ident] => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_self $_self => ) $txn_owner_name ) $statements [ ] $cells }
+    };
+    {
+        use [$_self:ident] => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_self $_self => ) __txn_owner ) $statements [ ] $cells }
+    };
+    {
+        let $txn_owner_name:ident = ( $owner_expr:expr ) => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_expr $owner_expr => ) $txn_owner_name ) $statements [ ] $cells }
+    };
+    {
+        use ( $owner_expr:expr ) => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_expr $owner_expr => ) __txn_owner ) $statements [ ] $cells }
+    };
+    {
+        let $txn_owner_name:ident = $owner_type:ty => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_type $owner_type => ) $txn_owner_name ) $statements [ ] $cells }
+    };
+    {
+        use $owner_type:ty => $cells:tt $statements:block $(;)?
+    } => {
+        $crate::c_txn! { @parse ( bind ( @from_type $owner_type => ) __txn_owner ) $statements [ ] $cells }
+    }
+}
+
+/// This module contains empty structs which match the types of cell groups
+/// accepted by [`def_cells`], for those who need something for their linters to
+/// validate against.
+/// 
+/// [`def_cells`]: ./macro.def_cells.html
+mod cell_grp_types {
+    /// Represents a uniform group of [`TCell`]s for linters.
+    /// 
+    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+    #[allow(dead_code)]
+    pub struct TCellUniGrp;
+    /// Represents a uniform group of [`TLCell`]s for linters.
+    /// 
+    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+    #[allow(dead_code)]
+    pub struct TLCellUniGrp;
+    /// Represents an access group of [`TCell`]s for linters.
+    /// 
+    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+    #[allow(dead_code)]
+    pub struct TCellAccGrp;
+    /// Represents an access group of [`TLCell`]s for linters.
+    /// 
+    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+    #[allow(dead_code)]
+    pub struct TLCellAccGrp;
+    /// Represents a public group of [`TCell`]s for linters.
+    /// 
+    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+    #[allow(dead_code)]
+    pub struct TCellPubGrp;
+    /// Represents a public group of [`TLCell`]s for linters.
+    /// 
+    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+    #[allow(dead_code)]
+    pub struct TLCellPubGrp;
+    /// Represents a private group of [`TCell`]s for linters.
+    /// 
+    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+    #[allow(dead_code)]
+    pub struct TCellPvtGrp;
+    /// Represents a private group of [`TLCell`]s for linters.
+    /// 
+    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+    #[allow(dead_code)]
+    pub struct TLCellPvtGrp;
+}
+
+/// Represents the [`TCell`], [`TLCell`], [`QCell`], and [`LCell`] implementations.
+/// "GT" ("General-T") refers to the possibility of either T or TL,
+/// and is not represented here.
+/// 
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`QCell`]: https://docs.rs/qcell/latest/qcell/struct.QCell.html
+/// [`LCell`]: https://docs.rs/qcell/latest/qcell/struct.LCell.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum CellImpl {
+    /// Indicates the [`TCell`] implementation.
+    /// 
+    /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+    T,
+    /// Indicates the [`TLCell`] implementation.
+    /// 
+    /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+    TL,
+    /// Indicates the [`QCell`] implementation.
+    /// 
+    /// [`QCell`]: https://docs.rs/qcell/latest/qcell/struct.QCell.html
+    Q,
+    /// Indicates the [`LCell`] implementation.
+    /// 
+    /// [`LCell`]: https://docs.rs/qcell/latest/qcell/struct.LCell.html
+    L
+}
+
+/// Represents the default access types for cell groups.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CellAccessLevels {
+    /// Uniform cell groups are general-purpose, with no implied
+    /// restrictions or organization schemes.
+    Uniform,
+    /// Private cell groups are implied to be used by same-`struct` methods.
+    Private,
+    /// Public cell groups are intended for program-wide accessibility.
+    Public
+}
+
+/// Represents the roles in the [`TCell`]/[`TLCell`] ecosystem.
+/// 
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CellRoles {
+    /// Markers restrict owner creation.
+    Marker,
+    /// Owners facilitate cell access.
+    Owner,
+    // Cells contain data.
+    Cell
+}
+
+// These are here for execution management and organization.
+
+/// This `trait` allows for standardized polling of implementation type,
+/// and indicates the [`TCell`] implementation.
+/// 
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+pub trait IsTImpl {
     #[inline]
     fn get_cell_impl() -> CellImpl {
         CellImpl::T
@@ -1005,6 +1670,36 @@ pub trait IsTLImpl {
     }
 }
 
+/// This `trait` allows for standardized polling of implementation type,
+/// and indicates the [`QCell`] implementation.
+/// 
+/// [`QCell`]: https://docs.rs/qcell/latest/qcell/struct.QCell.html
+pub trait IsQImpl {
+    #[inline]
+    fn get_cell_impl() -> CellImpl {
+        CellImpl::Q
+    }
+    #[inline]
+    fn get_self_cell_impl(&self) -> CellImpl {
+        CellImpl::Q
+    }
+}
+
+/// This `trait` allows for standardized polling of implementation type,
+/// and indicates the [`LCell`] implementation.
+/// 
+/// [`LCell`]: https://docs.rs/qcell/latest/qcell/struct.LCell.html
+pub trait IsLImpl {
+    #[inline]
+    fn get_cell_impl() -> CellImpl {
+        CellImpl::L
+    }
+    #[inline]
+    fn get_self_cell_impl(&self) -> CellImpl {
+        CellImpl::L
+    }
+}
+
 /// This `trait` allows for standardized polling of access type,
 /// and indicates the private access type.
 pub trait IsGTPvtAccess {
@@ -1169,6 +1864,8 @@ pub trait IsTLOwner : IsGTOwner + IsTLImpl {}
 pub trait IsTLUniOwner : IsTLOwner + IsTLUniAccess {}
 pub trait IsTLPubOwner : IsTLOwner + IsTLPubAccess {}
 pub trait IsTLPvtOwner : IsTLOwner + IsTLPvtAccess {}
+pub trait IsQOwner : IsGTOwner + IsQImpl {}
+pub trait IsLOwner : IsGTOwner + IsLImpl {}
 
 /// This `trait` allows for standardized polling for cell
 /// ecosystem role, and indicates a cell.
@@ -1193,6 +1890,8 @@ pub trait IsTLCell : IsGTCell + IsTLImpl {}
 pub trait IsTLUniCell : IsTLCell + IsTLUniAccess {}
 pub trait IsTLPubCell : IsTLCell + IsTLPubAccess {}
 pub trait IsTLPvtCell : IsTLCell + IsTLPvtAccess {}
+pub trait IsQCell : IsGTCell + IsQImpl {}
+pub trait IsLCell : IsGTCell + IsLImpl {}
 
 /// This `trait` allows an implementor to contextually request a private
 /// owner from a provider.
@@ -1218,25 +1917,414 @@ pub trait GetEasyUniOwner {
     fn get_matching_owner_from(&self, src : & impl GetUniOwner<Self::OwnerType>) -> Self::OwnerType;
 }
 
-// Implementation defaults for qcell contents
-impl<Q, T> IsTImpl for qcell::TCell<Q, T> where T : ?Sized {}
-impl<Q, T> IsGTCell for qcell::TCell<Q, T> where T : ?Sized {}
-impl<Q, T> IsTCell for qcell::TCell<Q, T> where T : ?Sized {}
-
-impl<Q, T> IsTLImpl for qcell::TLCell<Q, T> where T : ?Sized {}
-impl<Q, T> IsGTCell for qcell::TLCell<Q, T> where T : ?Sized {}
-impl<Q, T> IsTLCell for qcell::TLCell<Q, T> where T : ?Sized {}
+/// This `trait` associates a marker with the owner `type` that was declared
+/// alongside it, which allows cells under that marker to find their own
+/// matching owner. The macros of this crate implement it for every marker
+/// which has a known owner.
+pub trait CellGroup {
+    type Owner;
+    fn new_owner() -> Self::Owner;
+}
 
-impl<Q> IsTImpl for qcell::TCellOwner<Q> {}
-impl<Q> IsGTOwner for qcell::TCellOwner<Q> {}
-impl<Q> IsTOwner for qcell::TCellOwner<Q> {}
+/// This `trait` provides the hooks which are run by owner newtypes, as
+/// declared by [`def_cells`] with `owners: newtype`, whenever one of them is
+/// created or dropped. Each hook receives the `GROUP` path of the owner.
+/// 
+/// Both hooks do nothing by default, and `()` is used when a group does not
+/// provide its own hooks.
+/// 
+/// [`def_cells`]: ./macro.def_cells.html
+pub trait OwnerHooks {
+    #[inline]
+    fn on_acquire(group : &'static str) {
+        let _ = group;
+    }
+    #[inline]
+    fn on_release(group : &'static str) {
+        let _ = group;
+    }
+}
 
-impl<Q> IsTLImpl for qcell::TLCellOwner<Q> {}
-impl<Q> IsGTOwner for qcell::TLCellOwner<Q> {}
-impl<Q> IsTLOwner for qcell::TLCellOwner<Q> {}
+impl OwnerHooks for () {}
 
-/// The trait implemented by [`impl_get_pvt`], turning a `struct`
-/// in a private owner provider.
+/// This `trait` lets code which is generic over a [`CellGroup`] create and
+/// access the cells of that group through its owner, no matter whether the
+/// group uses [`TCell`]s or [`TLCell`]s. It is implemented for the owners of
+/// both, and for owners declared by [`def_cells`] with `owners: newtype`.
+/// 
+/// The cell collections of this crate use it to find the cell `type` of the
+/// group that they are keyed to.
+/// 
+/// # Safety
+/// 
+/// [`rw`] must return references which never alias for distinct cells, so
+/// that the references it returns for several cells can be held at once,
+/// for as long as the owner is borrowed mutably. The iterators of this
+/// crate's collections rely on this.
+/// 
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`def_cells`]: ./macro.def_cells.html
+/// [`rw`]: #tymethod.rw
+pub unsafe trait GroupOwner {
+    type Cell<T>;
+    fn new_cell<T>(item : T) -> Self::Cell<T>;
+    fn ro<'a, T>(&'a self, cell : &'a Self::Cell<T>) -> &'a T;
+    fn rw<'a, T>(&'a mut self, cell : &'a Self::Cell<T>) -> &'a mut T;
+    fn get_mut<T>(cell : &mut Self::Cell<T>) -> &mut T;
+    fn into_inner<T>(cell : Self::Cell<T>) -> T;
+}
+
+// Conjunction traits are derived from the role, implementation, and
+// access of a type, so only those three need to be implemented by hand.
+impl<X> IsTPvtAccess for X where X : IsGTPvtAccess + IsTImpl + ?Sized {}
+impl<X> IsTLPvtAccess for X where X : IsGTPvtAccess + IsTLImpl + ?Sized {}
+impl<X> IsTUniAccess for X where X : IsGTUniAccess + IsTImpl + ?Sized {}
+impl<X> IsTLUniAccess for X where X : IsGTUniAccess + IsTLImpl + ?Sized {}
+impl<X> IsTPubAccess for X where X : IsGTPubAccess + IsTImpl + ?Sized {}
+impl<X> IsTLPubAccess for X where X : IsGTPubAccess + IsTLImpl + ?Sized {}
+
+impl<X> IsGTUniMarker for X where X : IsGTMarker + IsGTUniAccess + ?Sized {}
+impl<X> IsGTPubMarker for X where X : IsGTMarker + IsGTPubAccess + ?Sized {}
+impl<X> IsGTPvtMarker for X where X : IsGTMarker + IsGTPvtAccess + ?Sized {}
+impl<X> IsTMarker for X where X : IsGTMarker + IsTImpl + ?Sized {}
+impl<X> IsTUniMarker for X where X : IsTMarker + IsTUniAccess + ?Sized {}
+impl<X> IsTPubMarker for X where X : IsTMarker + IsTPubAccess + ?Sized {}
+impl<X> IsTPvtMarker for X where X : IsTMarker + IsTPvtAccess + ?Sized {}
+impl<X> IsTLMarker for X where X : IsGTMarker + IsTLImpl + ?Sized {}
+impl<X> IsTLUniMarker for X where X : IsTLMarker + IsTLUniAccess + ?Sized {}
+impl<X> IsTLPubMarker for X where X : IsTLMarker + IsTLPubAccess + ?Sized {}
+impl<X> IsTLPvtMarker for X where X : IsTLMarker + IsTLPvtAccess + ?Sized {}
+
+impl<X> IsGTUniOwner for X where X : IsGTOwner + IsGTUniAccess + ?Sized {}
+impl<X> IsGTPubOwner for X where X : IsGTOwner + IsGTPubAccess + ?Sized {}
+impl<X> IsGTPvtOwner for X where X : IsGTOwner + IsGTPvtAccess + ?Sized {}
+impl<X> IsTOwner for X where X : IsGTOwner + IsTImpl + ?Sized {}
+impl<X> IsTUniOwner for X where X : IsTOwner + IsTUniAccess + ?Sized {}
+impl<X> IsTPubOwner for X where X : IsTOwner + IsTPubAccess + ?Sized {}
+impl<X> IsTPvtOwner for X where X : IsTOwner + IsTPvtAccess + ?Sized {}
+impl<X> IsTLOwner for X where X : IsGTOwner + IsTLImpl + ?Sized {}
+impl<X> IsTLUniOwner for X where X : IsTLOwner + IsTLUniAccess + ?Sized {}
+impl<X> IsTLPubOwner for X where X : IsTLOwner + IsTLPubAccess + ?Sized {}
+impl<X> IsTLPvtOwner for X where X : IsTLOwner + IsTLPvtAccess + ?Sized {}
+impl<X> IsQOwner for X where X : IsGTOwner + IsQImpl + ?Sized {}
+impl<X> IsLOwner for X where X : IsGTOwner + IsLImpl + ?Sized {}
+
+impl<X> IsGTUniCell for X where X : IsGTCell + IsGTUniAccess + ?Sized {}
+impl<X> IsGTPubCell for X where X : IsGTCell + IsGTPubAccess + ?Sized {}
+impl<X> IsGTPvtCell for X where X : IsGTCell + IsGTPvtAccess + ?Sized {}
+impl<X> IsTCell for X where X : IsGTCell + IsTImpl + ?Sized {}
+impl<X> IsTUniCell for X where X : IsTCell + IsTUniAccess + ?Sized {}
+impl<X> IsTPubCell for X where X : IsTCell + IsTPubAccess + ?Sized {}
+impl<X> IsTPvtCell for X where X : IsTCell + IsTPvtAccess + ?Sized {}
+impl<X> IsTLCell for X where X : IsGTCell + IsTLImpl + ?Sized {}
+impl<X> IsTLUniCell for X where X : IsTLCell + IsTLUniAccess + ?Sized {}
+impl<X> IsTLPubCell for X where X : IsTLCell + IsTLPubAccess + ?Sized {}
+impl<X> IsTLPvtCell for X where X : IsTLCell + IsTLPvtAccess + ?Sized {}
+impl<X> IsQCell for X where X : IsGTCell + IsQImpl + ?Sized {}
+impl<X> IsLCell for X where X : IsGTCell + IsLImpl + ?Sized {}
+
+// Implementation defaults for qcell contents.
+// Cells and owners take their access type from their marker.
+impl<Q, T> IsTImpl for qcell::TCell<Q, T> where T : ?Sized {}
+impl<Q, T> IsGTCell for qcell::TCell<Q, T> where T : ?Sized {}
+impl<Q, T> IsGTPvtAccess for qcell::TCell<Q, T> where Q : IsGTPvtAccess, T : ?Sized {}
+impl<Q, T> IsGTUniAccess for qcell::TCell<Q, T> where Q : IsGTUniAccess, T : ?Sized {}
+impl<Q, T> IsGTPubAccess for qcell::TCell<Q, T> where Q : IsGTPubAccess, T : ?Sized {}
+
+#[cfg(feature = "std")]
+impl<Q, T> IsTLImpl for qcell::TLCell<Q, T> where T : ?Sized {}
+#[cfg(feature = "std")]
+impl<Q, T> IsGTCell for qcell::TLCell<Q, T> where T : ?Sized {}
+#[cfg(feature = "std")]
+impl<Q, T> IsGTPvtAccess for qcell::TLCell<Q, T> where Q : IsGTPvtAccess, T : ?Sized {}
+#[cfg(feature = "std")]
+impl<Q, T> IsGTUniAccess for qcell::TLCell<Q, T> where Q : IsGTUniAccess, T : ?Sized {}
+#[cfg(feature = "std")]
+impl<Q, T> IsGTPubAccess for qcell::TLCell<Q, T> where Q : IsGTPubAccess, T : ?Sized {}
+
+impl<T> IsQImpl for qcell::QCell<T> where T : ?Sized {}
+impl<T> IsGTCell for qcell::QCell<T> where T : ?Sized {}
+
+impl<'id, T> IsLImpl for qcell::LCell<'id, T> where T : ?Sized {}
+impl<'id, T> IsGTCell for qcell::LCell<'id, T> where T : ?Sized {}
+
+impl<Q> IsTImpl for qcell::TCellOwner<Q> {}
+impl<Q> IsGTOwner for qcell::TCellOwner<Q> {}
+impl<Q> IsGTPvtAccess for qcell::TCellOwner<Q> where Q : IsGTPvtAccess {}
+impl<Q> IsGTUniAccess for qcell::TCellOwner<Q> where Q : IsGTUniAccess {}
+impl<Q> IsGTPubAccess for qcell::TCellOwner<Q> where Q : IsGTPubAccess {}
+
+#[cfg(feature = "std")]
+impl<Q> IsTLImpl for qcell::TLCellOwner<Q> {}
+#[cfg(feature = "std")]
+impl<Q> IsGTOwner for qcell::TLCellOwner<Q> {}
+#[cfg(feature = "std")]
+impl<Q> IsGTPvtAccess for qcell::TLCellOwner<Q> where Q : IsGTPvtAccess {}
+#[cfg(feature = "std")]
+impl<Q> IsGTUniAccess for qcell::TLCellOwner<Q> where Q : IsGTUniAccess {}
+#[cfg(feature = "std")]
+impl<Q> IsGTPubAccess for qcell::TLCellOwner<Q> where Q : IsGTPubAccess {}
+
+#[cfg(feature = "alloc")]
+impl IsQImpl for qcell::QCellOwner {}
+#[cfg(feature = "alloc")]
+impl IsGTOwner for qcell::QCellOwner {}
+
+impl<'id> IsLImpl for qcell::LCellOwner<'id> {}
+impl<'id> IsGTOwner for qcell::LCellOwner<'id> {}
+
+// Cells find their matching owner through their marker.
+impl<Q, T> GetEasyPvtOwner for qcell::TCell<Q, T>
+where
+    Q : IsGTPvtAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetPvtOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_private_owner()
+    }
+}
+
+impl<Q, T> GetEasyUniOwner for qcell::TCell<Q, T>
+where
+    Q : IsGTUniAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetUniOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_uniform_owner()
+    }
+}
+
+impl<Q, T> GetEasyPubOwner for qcell::TCell<Q, T>
+where
+    Q : IsGTPubAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetPubOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_public_owner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Q, T> GetEasyPvtOwner for qcell::TLCell<Q, T>
+where
+    Q : IsGTPvtAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetPvtOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_private_owner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Q, T> GetEasyUniOwner for qcell::TLCell<Q, T>
+where
+    Q : IsGTUniAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetUniOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_uniform_owner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Q, T> GetEasyPubOwner for qcell::TLCell<Q, T>
+where
+    Q : IsGTPubAccess + CellGroup,
+    T : ?Sized
+{
+    type OwnerType = Q::Owner;
+
+    #[inline]
+    fn get_new_matching_owner(&self) -> Self::OwnerType {
+        Q::new_owner()
+    }
+
+    #[inline]
+    fn get_matching_owner_from(&self, src : & impl GetPubOwner<Self::OwnerType>) -> Self::OwnerType {
+        src.get_public_owner()
+    }
+}
+
+// SAFETY: Each cell holds its own contents, which `rw` borrows.
+unsafe impl<Q> GroupOwner for qcell::TCellOwner<Q> where Q : 'static {
+    type Cell<T> = qcell::TCell<Q, T>;
+
+    #[inline]
+    fn new_cell<T>(item : T) -> Self::Cell<T> {
+        qcell::TCell::new(item)
+    }
+
+    #[inline]
+    fn ro<'a, T>(&'a self, cell : &'a Self::Cell<T>) -> &'a T {
+        cell.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a, T>(&'a mut self, cell : &'a Self::Cell<T>) -> &'a mut T {
+        cell.rw(self)
+    }
+
+    #[inline]
+    fn get_mut<T>(cell : &mut Self::Cell<T>) -> &mut T {
+        cell.get_mut()
+    }
+
+    #[inline]
+    fn into_inner<T>(cell : Self::Cell<T>) -> T {
+        cell.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+// SAFETY: Each cell holds its own contents, which `rw` borrows.
+unsafe impl<Q> GroupOwner for qcell::TLCellOwner<Q> where Q : 'static {
+    type Cell<T> = qcell::TLCell<Q, T>;
+
+    #[inline]
+    fn new_cell<T>(item : T) -> Self::Cell<T> {
+        qcell::TLCell::new(item)
+    }
+
+    #[inline]
+    fn ro<'a, T>(&'a self, cell : &'a Self::Cell<T>) -> &'a T {
+        cell.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a, T>(&'a mut self, cell : &'a Self::Cell<T>) -> &'a mut T {
+        cell.rw(self)
+    }
+
+    #[inline]
+    fn get_mut<T>(cell : &mut Self::Cell<T>) -> &mut T {
+        cell.get_mut()
+    }
+
+    #[inline]
+    fn into_inner<T>(cell : Self::Cell<T>) -> T {
+        cell.into_inner()
+    }
+}
+
+/// This `trait` describes a cell which is read and written through an owner
+/// of a known `type`, which lets adapters pair any cell with its owner. It
+/// is implemented for the cells of [`qcell`], and for the cells declared by
+/// [`def_cells`] with `cells: newtype`.
+/// 
+/// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
+/// [`def_cells`]: ./macro.def_cells.html
+pub trait OwnedCell {
+    type Owner;
+    type Value : ?Sized;
+    fn ro<'a>(&'a self, owner : &'a Self::Owner) -> &'a Self::Value;
+    fn rw<'a>(&'a self, owner : &'a mut Self::Owner) -> &'a mut Self::Value;
+}
+
+impl<Q, T> OwnedCell for qcell::TCell<Q, T> where Q : 'static, T : ?Sized {
+    type Owner = qcell::TCellOwner<Q>;
+    type Value = T;
+
+    #[inline]
+    fn ro<'a>(&'a self, owner : &'a Self::Owner) -> &'a T {
+        owner.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a>(&'a self, owner : &'a mut Self::Owner) -> &'a mut T {
+        owner.rw(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Q, T> OwnedCell for qcell::TLCell<Q, T> where Q : 'static, T : ?Sized {
+    type Owner = qcell::TLCellOwner<Q>;
+    type Value = T;
+
+    #[inline]
+    fn ro<'a>(&'a self, owner : &'a Self::Owner) -> &'a T {
+        owner.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a>(&'a self, owner : &'a mut Self::Owner) -> &'a mut T {
+        owner.rw(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OwnedCell for qcell::QCell<T> where T : ?Sized {
+    type Owner = qcell::QCellOwner;
+    type Value = T;
+
+    #[inline]
+    fn ro<'a>(&'a self, owner : &'a Self::Owner) -> &'a T {
+        owner.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a>(&'a self, owner : &'a mut Self::Owner) -> &'a mut T {
+        owner.rw(self)
+    }
+}
+
+impl<'id, T> OwnedCell for qcell::LCell<'id, T> where T : ?Sized {
+    type Owner = qcell::LCellOwner<'id>;
+    type Value = T;
+
+    #[inline]
+    fn ro<'a>(&'a self, owner : &'a Self::Owner) -> &'a T {
+        owner.ro(self)
+    }
+
+    #[inline]
+    fn rw<'a>(&'a self, owner : &'a mut Self::Owner) -> &'a mut T {
+        owner.rw(self)
+    }
+}
+
+/// The trait implemented by [`impl_get_pvt`], turning a `struct`
+/// in a private owner provider.
 /// 
 /// [`impl_get_pvt`]: ./macro.impl_get_pvt.html
 pub trait GetPvtOwner<T> {
@@ -1335,58 +2423,100 @@ macro_rules! impl_get_uni {
 /// 
 /// // Also allows for attributes
 /// pub new_t_marker_type!(#[allow(dead_code)] MarkerTypeName);
+/// 
+/// // Markers are uniform by default, but can also be private or public
+/// new_t_marker_type!(pvt PrivateMarkerTypeName);
+/// new_t_marker_type!(pub pub PublicMarkerTypeName);
 /// ```
 /// 
+/// The subcategory keyword (`uni`, `pvt`, or `pub`) goes after the
+/// visibility, so a lone `pub` is always read as the visibility.
+/// 
 /// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
 /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_t_marker_type {
     {
-        @finish_build => ( $marker_name:ident )
+        @finish_build => ( $marker_name:ident , uni )
+    } => {
+        $crate::new_t_marker_type! { @finish_build => ( $marker_name , IsGTUniAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , pvt )
+    } => {
+        $crate::new_t_marker_type! { @finish_build => ( $marker_name , IsGTPvtAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , pub )
+    } => {
+        $crate::new_t_marker_type! { @finish_build => ( $marker_name , IsGTPubAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , $access_type:ident )
     } => {
         impl $crate::IsGTMarker for $marker_name {}
-        impl $crate::IsTMarker for $marker_name {}
         impl $crate::IsTImpl for $marker_name {}
-        impl $crate::IsGTUniAccess for $marker_name {}
-        impl $crate::IsTUniAccess for $marker_name {}
-        impl $crate::IsTUniMarker for $marker_name {}
+        impl $crate::$access_type for $marker_name {}
     };
     {
-        $( # [ $attr:meta ] )* $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis type $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
-        pub struct $marker_name;
         $crate::new_t_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => $access ( $( #[$attr] )* $visibilty $marker_name )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis struct $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty struct $marker_name;
         $crate::new_t_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => $access ( $( #[$attr] )* $visibilty $marker_name )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis type $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
+        $( #[$attr] )*
         $visibilty struct $marker_name;
         $crate::new_t_marker_type! {
-            @finish_build => ( $marker_name )
+            @finish_build => ( $marker_name , $access )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis struct $marker_name:ident
+        $( # [ $attr:meta ] )* $marker_name:ident
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty struct $marker_name;
         $crate::new_t_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => uni ( $( #[$attr] )* pub $marker_name )
         }
-    }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis uni $( $rest:tt )+
+    } => {
+        $crate::new_t_marker_type! {
+            @build => uni ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pvt $( $rest:tt )+
+    } => {
+        $crate::new_t_marker_type! {
+            @build => pvt ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pub $( $rest:tt )+
+    } => {
+        $crate::new_t_marker_type! {
+            @build => pub ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis $first:ident $( $rest:tt )*
+    } => {
+        $crate::new_t_marker_type! {
+            @build => uni ( $( #[$attr] )* $visibilty $first $( $rest )* )
+        }
+    };
 }
 
 /// This macro creates a new [`qcell`] marker, with all the
@@ -1399,58 +2529,110 @@ macro_rules! new_t_marker_type {
 /// 
 /// // Also allows for attributes
 /// pub new_tl_marker_type!(#[allow(dead_code)] MarkerTypeName);
+/// 
+/// // Markers are uniform by default, but can also be private or public
+/// new_tl_marker_type!(pvt PrivateMarkerTypeName);
+/// new_tl_marker_type!(pub pub PublicMarkerTypeName);
+/// ```
+/// 
+/// The subcategory keyword (`uni`, `pvt`, or `pub`) goes after the
+/// visibility, so a lone `pub` is always read as the visibility.
+/// 
+/// Without a visibility, the marker stays private to its module:
+/// 
+/// ```rust,compile_fail
+/// mod markers {
+///     cell_wrappers::new_tl_marker_type!(HiddenMarker);
+/// }
+/// 
+/// let _ = markers::HiddenMarker;
 /// ```
 /// 
 /// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
 /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_tl_marker_type {
     {
-        @finish_build => ( $marker_name:ident ) 
+        @finish_build => ( $marker_name:ident , uni )
+    } => {
+        $crate::new_tl_marker_type! { @finish_build => ( $marker_name , IsGTUniAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , pvt )
+    } => {
+        $crate::new_tl_marker_type! { @finish_build => ( $marker_name , IsGTPvtAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , pub )
+    } => {
+        $crate::new_tl_marker_type! { @finish_build => ( $marker_name , IsGTPubAccess ) }
+    };
+    {
+        @finish_build => ( $marker_name:ident , $access_type:ident )
     } => {
         impl $crate::IsGTMarker for $marker_name {}
-        impl $crate::IsTLMarker for $marker_name {}
         impl $crate::IsTLImpl for $marker_name {}
-        impl $crate::IsGTUniAccess for $marker_name {}
-        impl $crate::IsTLUniAccess for $marker_name {}
-        impl $crate::IsTLUniMarker for $marker_name {}
+        impl $crate::$access_type for $marker_name {}
     };
     {
-        $( # [ $attr:meta ] )* $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis type $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
-        struct $marker_name;
         $crate::new_tl_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => $access ( $( #[$attr] )* $visibilty $marker_name )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis struct $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty struct $marker_name;
         $crate::new_tl_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => $access ( $( #[$attr] )* $visibilty $marker_name )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis type $marker_name:ident
+        @build => $access:tt ( $( # [ $attr:meta ] )* $visibilty:vis $marker_name:ident )
     } => {
-        $( # [ $attr:meta ] )*
+        $( #[$attr] )*
         $visibilty struct $marker_name;
         $crate::new_tl_marker_type! {
-            @finish_build => ( $marker_name )
+            @finish_build => ( $marker_name , $access )
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis struct $marker_name:ident
+        $( # [ $attr:meta ] )* $marker_name:ident
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty struct $marker_name;
         $crate::new_tl_marker_type! {
-            @finish_build => ( $marker_name )
+            @build => uni ( $( #[$attr] )* $marker_name )
         }
-    }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis uni $( $rest:tt )+
+    } => {
+        $crate::new_tl_marker_type! {
+            @build => uni ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pvt $( $rest:tt )+
+    } => {
+        $crate::new_tl_marker_type! {
+            @build => pvt ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pub $( $rest:tt )+
+    } => {
+        $crate::new_tl_marker_type! {
+            @build => pub ( $( #[$attr] )* $visibilty $( $rest )+ )
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis $first:ident $( $rest:tt )*
+    } => {
+        $crate::new_tl_marker_type! {
+            @build => uni ( $( #[$attr] )* $visibilty $first $( $rest )* )
+        }
+    };
 }
 
 /// This macro creates a new [`qcell`] owner, with all the
@@ -1467,41 +2649,26 @@ macro_rules! new_tl_marker_type {
 /// 
 /// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
 /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_t_owner_type {
-    {
-        @finish_build => ( $owner_name:ident [ $marker_name:ident ] )
-    } => {
-        impl $crate::IsGTUniAccess for $owner_name {}
-        impl $crate::IsTUniAccess for $owner_name {}
-        impl $crate::IsTUniOwner for $owner_name {}
-    };
     {
         $( # [ $attr:meta ] )* $owner_name:ident [ $marker_name:ident ]
      } => {
-        $( # [ $attr:meta ] )*
-        type $owner_name = qcell::TCellOwner<$marker_name>;
-        $crate::new_t_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        type $owner_name = $crate::qcell::TCellOwner<$marker_name>;
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $owner_name:ident [ $marker_name:ident ]
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $owner_name = qcell::TCellOwner<$marker_name>;
-        $crate::new_t_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        $visibilty type $owner_name = $crate::qcell::TCellOwner<$marker_name>;
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis type $owner_name:ident [ $marker_name:ident ]
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $owner_name = qcell::TCellOwner<$marker_name>;
-        $crate::new_t_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        $visibilty type $owner_name = $crate::qcell::TCellOwner<$marker_name>;
     };
 }
 
@@ -1519,117 +2686,177 @@ macro_rules! new_t_owner_type {
 /// 
 /// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
 /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_tl_owner_type {
-    {
-        @finish_build => ( $owner_name:ident [ $marker_name:ident ] )
-    } => {
-        impl $crate::IsGTUniAccess for $owner_name {}
-        impl $crate::IsTLUniAccess for $owner_name {}
-        impl $crate::IsTLUniOwner for $owner_name {}
-    };
     {
         $( # [ $attr:meta ] )* $owner_name:ident [ $marker_name:ident ]
     } => {
-        $( # [ $attr:meta ] )*
-        type $owner_name = qcell::TLCellOwner<$marker_name>;
-        $crate::new_tl_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        type $owner_name = $crate::qcell::TLCellOwner<$marker_name>;
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $owner_name:ident [ $marker_name:ident ]
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $owner_name = qcell::TLCellOwner<$marker_name>;
-        $crate::new_tl_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        $visibilty type $owner_name = $crate::qcell::TLCellOwner<$marker_name>;
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis type $owner_name:ident [ $marker_name:ident ]
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $owner_name = qcell::TLCellOwner<$marker_name>;
-        $crate::new_tl_owner_type! {
-            @finish_build => ( $owner_name [ $marker_name ] )
-        }
+        $( #[$attr] )*
+        $visibilty type $owner_name = $crate::qcell::TLCellOwner<$marker_name>;
     };
 }
 
 /// This macro creates a new [`qcell`] [`TCell`], with all the
 /// convenience `trait`s for compatibility with this crate.
 /// 
-/// ```rust
+/// ```ignore
 /// new_t_cell_type!(CellTypeName<T>[MarkerTypeName]);
 /// 
 /// // Also allows for attributes
 /// pub new_t_cell_type!(#[allow(dead_code)] CellTypeName<T>[MarkerTypeName]);
 /// ```
 /// 
-/// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
-/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
-#[macro_export]
-macro_rules! new_t_cell_type {
-    {
-        @finish_build => ( $cell_name:ident < $cell_type:ident > [ $marker_name:ident ] )
-    } => {
-        impl<$cell_type> $crate::IsGTUniAccess for $cell_name<$cell_type> {}
-        impl<$cell_type> $crate::IsTUniAccess for $cell_name<$cell_type> {}
-        impl<$cell_type> $crate::IsTUniCell for $cell_name<$cell_type> {}
-    };
+/// Several generic parameters, lifetimes, and bounds are allowed too, with
+/// the contents of the cell given after a `=>`, unless the only parameter
+/// is the contents:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// use std::hash::Hash;
+/// 
+/// new_t_marker_type!(StoreMarker);
+/// new_t_owner_type!(StoreOwner[StoreMarker]);
+/// new_t_cell_type!(pub Slot<'a, K: Hash, V: ?Sized>[StoreMarker] => (&'a K, Box<V>));
+/// new_t_cell_type!(pub Batch<T: Into<Vec<u8>>>[StoreMarker] => Vec<T>);
+/// 
+/// let key = String::from("greeting");
+/// let slot: Slot<String, str> = Slot::new((&key, Box::from("hello")));
+/// let batch: Batch<&str> = Batch::new(vec!["a", "b"]);
+/// 
+/// let owner = StoreOwner::new();
+/// assert_eq!(&*slot.ro(&owner).1, "hello");
+/// assert_eq!(batch.ro(&owner).len(), 2);
+/// ```
+/// 
+/// Like any `type` alias, the bounds of the generic parameters are not
+/// enforced, and only serve as documentation. Unsized contents, like
+/// `CellTypeName<dyn Trait>`, can be placed behind a `Box` or `Rc`.
+/// 
+/// Naming the owner, with an `owner` argument, lets [`c_scp`] find it with
+/// the `_` and `[self]` sources. The `owner` argument implements
+/// [`CellGroup`] for the marker, which in turn provides the matching
+/// `GetEasy...Owner` `trait` for the cell type. Since a marker only has one
+/// owner, only one cell type per marker should be given the `owner`
+/// argument. [`new_t_group`] already does this.
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// new_t_marker_type!(LedgerMarker);
+/// new_t_owner_type!(LedgerOwner[LedgerMarker]);
+/// new_t_cell_type!(LedgerCell<T>[LedgerMarker], owner: LedgerOwner);
+/// 
+/// let balance: LedgerCell<u32> = LedgerCell::new(5);
+/// c_scp! {
+///     use _ => (
+///         balance => mut cont
+///     ) {
+///         *cont += 1;
+///     }
+/// }
+/// assert_eq!(*balance.ro(&LedgerOwner::new()), 6);
+/// ```
+/// 
+/// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
+/// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`CellGroup`]: ./trait.CellGroup.html
+/// [`c_scp`]: ./macro.c_scp.html
+/// [`new_t_group`]: ./macro.new_t_group.html
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! new_t_cell_type {
+    {
+        @for_owner => ( $marker_name:ident , $owner_name:ident )
+    } => {
+        impl $crate::CellGroup for $marker_name {
+            type Owner = $owner_name;
+
+            #[inline]
+            fn new_owner() -> Self::Owner {
+                $owner_name::new()
+            }
+        }
+    };
+    {
+        @finish_build => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident )
+        [ $( $generics:tt )* ] [ $marker_name:ident ] ( $payload:ty )
+        $( , owner : $owner_name:ident )?
+    } => {
+        $( #[$attr] )*
+        #[allow(type_alias_bounds)]
+        $visibilty type $cell_name< $( $generics )* > = $crate::qcell::TCell<$marker_name, $payload>;
+        $( $crate::new_t_cell_type! { @for_owner => ( $marker_name , $owner_name ) } )?
+    };
+    // Generics splitter
     {
-        $( # [ $attr:meta ] )* $cell_name:ident < $cell_type:ident > [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ]
+        > [ $marker_name:ident ] => $payload:ty $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<$cell_type> = qcell::TCell<$marker_name, $cell_type>;
         $crate::new_t_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => $head [ $( $generics )* ] [ $marker_name ] ( $payload )
+            $( , owner : $owner_name )?
         }
     };
     {
-        $( # [ $attr:meta ] )* $cell_name:ident [ $marker_name:ident ] < $cell_type:ident >
+        @for_generics => $head:tt [ $param:ident $( : $( $bound:tt )* )? ]
+        > [ $marker_name:ident ] $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<$cell_type> = qcell::TCell<$marker_name, $cell_type>;
         $crate::new_t_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => $head [ $param $( : $( $bound )* )? ] [ $marker_name ] ( $param )
+            $( , owner : $owner_name )?
         }
     };
     {
-        $( # [ $attr:meta ] )* $cell_name:ident [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ] >> $( $rest:tt )*
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<T> = qcell::TCell<$marker_name, T>;
         $crate::new_t_cell_type! {
-            @finish_build => ( $cell_name < T > [ $marker_name ] )
+            @for_generics => $head [ $( $generics )* > ] > $( $rest )*
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident < $cell_type:ident > [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ] $next:tt $( $rest:tt )*
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<$cell_type> = qcell::TCell<$marker_name, $cell_type>;
-        $crate::new_t_cell_type!{
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+        $crate::new_t_cell_type! {
+            @for_generics => $head [ $( $generics )* $next ] $( $rest )*
         }
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident [ $marker_name:ident ] < $cell_type:ident >
+        $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<$cell_type> = qcell::TCell<$marker_name, $cell_type>;
         $crate::new_t_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => ( $( #[$attr] )* $visibilty $cell_name )
+            [ $cell_type ] [ $marker_name ] ( $cell_type )
+            $( , owner : $owner_name )?
         }
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident [ $marker_name:ident ]
+        $( , owner : $owner_name:ident )?
+    } => {
+        $crate::new_t_cell_type! {
+            @finish_build => ( $( #[$attr] )* $visibilty $cell_name )
+            [ T ] [ $marker_name ] ( T )
+            $( , owner : $owner_name )?
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident < $( $rest:tt )+
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<T> = qcell::TCell<$marker_name, T>;
         $crate::new_t_cell_type! {
-            @finish_build => ( $cell_name < T > [ $marker_name ] )
+            @for_generics => ( $( #[$attr] )* $visibilty $cell_name ) [ ] $( $rest )+
         }
     };
 }
@@ -1637,89 +2864,174 @@ macro_rules! new_t_cell_type {
 /// This macro creates a new [`qcell`] [`TLCell`], with all the
 /// convenience `trait`s for compatibility with this crate.
 /// 
-/// ```rust
+/// ```ignore
 /// new_tl_cell_type!(CellTypeName<T>[MarkerTypeName]);
 /// 
 /// // Also allows for attributes
 /// pub new_tl_cell_type!(#[allow(dead_code)] CellTypeName<T>[MarkerTypeName]);
 /// ```
 /// 
+/// Several generic parameters, lifetimes, and bounds are allowed too, with
+/// the contents of the cell given after a `=>`, unless the only parameter
+/// is the contents:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// use std::hash::Hash;
+/// 
+/// new_tl_marker_type!(StoreMarker);
+/// new_tl_owner_type!(StoreOwner[StoreMarker]);
+/// new_tl_cell_type!(pub Slot<'a, K: Hash, V: ?Sized>[StoreMarker] => (&'a K, Box<V>));
+/// new_tl_cell_type!(pub Batch<T: Into<Vec<u8>>>[StoreMarker] => Vec<T>);
+/// 
+/// let key = String::from("greeting");
+/// let slot: Slot<String, str> = Slot::new((&key, Box::from("hello")));
+/// let batch: Batch<&str> = Batch::new(vec!["a", "b"]);
+/// 
+/// let owner = StoreOwner::new();
+/// assert_eq!(&*slot.ro(&owner).1, "hello");
+/// assert_eq!(batch.ro(&owner).len(), 2);
+/// ```
+/// 
+/// Like any `type` alias, the bounds of the generic parameters are not
+/// enforced, and only serve as documentation. Unsized contents, like
+/// `CellTypeName<dyn Trait>`, can be placed behind a `Box` or `Rc`.
+/// 
+/// Naming the owner, with an `owner` argument, lets [`c_scp`] find it with
+/// the `_` and `[self]` sources. The `owner` argument implements
+/// [`CellGroup`] for the marker, which in turn provides the matching
+/// `GetEasy...Owner` `trait` for the cell type. Since a marker only has one
+/// owner, only one cell type per marker should be given the `owner`
+/// argument. [`new_tl_group`] already does this.
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// new_tl_marker_type!(LedgerMarker);
+/// new_tl_owner_type!(LedgerOwner[LedgerMarker]);
+/// new_tl_cell_type!(LedgerCell<T>[LedgerMarker], owner: LedgerOwner);
+/// 
+/// let balance: LedgerCell<u32> = LedgerCell::new(5);
+/// c_scp! {
+///     use _ => (
+///         balance => mut cont
+///     ) {
+///         *cont += 1;
+///     }
+/// }
+/// assert_eq!(*balance.ro(&LedgerOwner::new()), 6);
+/// ```
+/// 
 /// [`qcell`]: https://docs.rs/qcell/latest/qcell/index.html
 /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`CellGroup`]: ./trait.CellGroup.html
+/// [`c_scp`]: ./macro.c_scp.html
+/// [`new_tl_group`]: ./macro.new_tl_group.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_tl_cell_type {
     {
-        @finish_build => ( $cell_name:ident < $cell_type:ident > [ $marker_name:ident ] )
+        @for_owner => ( $marker_name:ident , $owner_name:ident )
+    } => {
+        impl $crate::CellGroup for $marker_name {
+            type Owner = $owner_name;
+
+            #[inline]
+            fn new_owner() -> Self::Owner {
+                $owner_name::new()
+            }
+        }
+    };
+    {
+        @finish_build => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident )
+        [ $( $generics:tt )* ] [ $marker_name:ident ] ( $payload:ty )
+        $( , owner : $owner_name:ident )?
     } => {
-        impl<$cell_type> $crate::IsGTUniAccess for $cell_name<$cell_type> {}
-        impl<$cell_type> $crate::IsTLUniAccess for $cell_name<$cell_type> {}
-        impl<$cell_type> $crate::IsTLUniCell for $cell_name<$cell_type> {}
+        $( #[$attr] )*
+        #[allow(type_alias_bounds)]
+        $visibilty type $cell_name< $( $generics )* > = $crate::qcell::TLCell<$marker_name, $payload>;
+        $( $crate::new_tl_cell_type! { @for_owner => ( $marker_name , $owner_name ) } )?
     };
+    // Generics splitter
     {
-        $( # [ $attr:meta ] )* $cell_name:ident < $cell_type:ident > [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ]
+        > [ $marker_name:ident ] => $payload:ty $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<$cell_type> = qcell::TLCell<$marker_name, $cell_type>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => $head [ $( $generics )* ] [ $marker_name ] ( $payload )
+            $( , owner : $owner_name )?
         }
     };
     {
-        $( # [ $attr:meta ] )* $cell_name:ident [ $marker_name:ident ] < $cell_type:ident >
+        @for_generics => $head:tt [ $param:ident $( : $( $bound:tt )* )? ]
+        > [ $marker_name:ident ] $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<$cell_type> = qcell::TLCell<$marker_name, $cell_type>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => $head [ $param $( : $( $bound )* )? ] [ $marker_name ] ( $param )
+            $( , owner : $owner_name )?
         }
     };
     {
-        $( # [ $attr:meta ] )* $cell_name:ident [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ] >> $( $rest:tt )*
     } => {
-        $( # [ $attr:meta ] )*
-        type $cell_name<T> = qcell::TLCell<$marker_name, T>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < T > [ $marker_name ] )
+            @for_generics => $head [ $( $generics )* > ] > $( $rest )*
         }
     };
     {
-        $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident < $cell_type:ident > [ $marker_name:ident ]
+        @for_generics => $head:tt [ $( $generics:tt )* ] $next:tt $( $rest:tt )*
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<$cell_type> = qcell::TLCell<$marker_name, $cell_type>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @for_generics => $head [ $( $generics )* $next ] $( $rest )*
         }
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident [ $marker_name:ident ] < $cell_type:ident >
+        $( , owner : $owner_name:ident )?
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<$cell_type> = qcell::TLCell<$marker_name, $cell_type>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < $cell_type > [ $marker_name ] )
+            @finish_build => ( $( #[$attr] )* $visibilty $cell_name )
+            [ $cell_type ] [ $marker_name ] ( $cell_type )
+            $( , owner : $owner_name )?
         }
     };
     {
         $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident [ $marker_name:ident ]
+        $( , owner : $owner_name:ident )?
+    } => {
+        $crate::new_tl_cell_type! {
+            @finish_build => ( $( #[$attr] )* $visibilty $cell_name )
+            [ T ] [ $marker_name ] ( T )
+            $( , owner : $owner_name )?
+        }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident < $( $rest:tt )+
     } => {
-        $( # [ $attr:meta ] )*
-        $visibilty type $cell_name<T> = qcell::TLCell<$marker_name, T>;
         $crate::new_tl_cell_type! {
-            @finish_build => ( $cell_name < T > [ $marker_name ] )
+            @for_generics => ( $( #[$attr] )* $visibilty $cell_name ) [ ] $( $rest )+
         }
     };
 }
 
-/// This macro creates a new uniform [`TCell`] cell group,
+/// This macro creates a new [`TCell`] cell group,
 /// without the creation of an inline module.
 /// 
+/// Groups are uniform by default, but a `uni`, `pvt`, or `pub` keyword
+/// after the visibility selects the subcategory instead. The cells of
+/// every group implement the matching [`GetEasyUniOwner`],
+/// [`GetEasyPvtOwner`], or [`GetEasyPubOwner`] `trait`, so they can be
+/// used with the `_` and `[self]` owner sources of [`c_scp`].
+/// 
 /// ```rust
 /// new_t_group!(OwnerTypeName[MarkerTypeName] => CellTypeName<T>);
 /// 
 /// // Also allows for attributes
 /// new_t_group!(#[allow(dead_code)] pub OwnerTypeName[MarkerTypeName] => CellTypeName<T>);
 /// 
+/// // Also allows for a subcategory
+/// new_t_group!(pvt PvtOwnerTypeName[PvtMarkerTypeName] => PvtCellTypeName<T>);
+/// new_t_group!(pub pub PubOwnerTypeName[PubMarkerTypeName] => PubCellTypeName<T>);
+/// 
 /// // Also allows for `struct`-like syntax, where individual components can
 /// // have different attributes
 /// new_t_group! {
@@ -1727,106 +3039,165 @@ macro_rules! new_tl_cell_type {
 ///     pub owner: OwnerTypeName,
 ///     #[allow(dead_code)] pub cell: CellTypeName<T>
 /// }
+/// 
+/// // The `struct`-like syntax takes the subcategory as an `access` entry,
+/// // which is one of `uniform`, `private`, or `public`
+/// new_t_group! {
+///     access: private,
+///     marker: PvtMarkerTypeName,
+///     owner: PvtOwnerTypeName,
+///     cell: PvtCellTypeName<T>
+/// }
 /// ```
 /// 
 /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+/// [`c_scp`]: ./macro.c_scp.html
+/// [`GetEasyUniOwner`]: ./trait.GetEasyUniOwner.html
+/// [`GetEasyPvtOwner`]: ./trait.GetEasyPvtOwner.html
+/// [`GetEasyPubOwner`]: ./trait.GetEasyPubOwner.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_t_group {
     {
-        $( # [ $attr:meta ] )* $visibilty:vis $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+        @build => $access:tt
+        ( $( # [ $marker_attr:meta ] )* $marker_visibilty:vis $marker_name:ident )
+        ( $( # [ $owner_attr:meta ] )* $owner_visibilty:vis $owner_name:ident )
+        ( $( # [ $cell_attr:meta ] )* $cell_visibilty:vis $cell_name:ident $( < $cell_type:ident > )? )
     } => {
-        $( # [ $attr:meta ] )*
         $crate::new_t_marker_type! {
-            $visibilty $marker_name
+            @build => $access ( $( #[$marker_attr] )* $marker_visibilty $marker_name )
         }
-        $( # [ $attr:meta ] )*
         $crate::new_t_owner_type! {
-            $visibilty $owner_name [ $marker_name ]
+            $( #[$owner_attr] )* $owner_visibilty $owner_name [ $marker_name ]
         }
-        $( # [ $attr:meta ] )*
         $crate::new_t_cell_type! {
-            $visibilty $cell_name $( < $cell_type > )? [ $marker_name ]
+            $( #[$cell_attr] )* $cell_visibilty $cell_name [ $marker_name ] $( < $cell_type > )?
+            , owner : $owner_name
         }
     };
+    // Struct-like syntax
     {
-        @for_individual => ( $visibilty:vis cell : $cell_name:ident $( < $cell_type:ident > )? [ $marker_name:ident ] )
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : uniform $( , $( $rest:tt )* )?
     } => {
-        $crate::new_t_cell_type! {
-            $visibilty $cell_name [ $marker_name ] $( < $cell_type > )?
-        }
+        $crate::new_t_group! { @munch [ $marker $owner $cell uni ] $( $( $rest )* )? }
     };
     {
-        @for_individual => ( $visibilty:vis owner : $owner_name:ident [ $marker_name:ident ] )
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : private $( , $( $rest:tt )* )?
     } => {
-        $crate::new_t_owner_type! {
-            $visibilty $owner_name [ $marker_name ]
-        }
+        $crate::new_t_group! { @munch [ $marker $owner $cell pvt ] $( $( $rest )* )? }
     };
     {
-        $( # [ $attr2:meta ] )* $visibilty2:vis marker : $marker_name:ident ,
-        $( # [ $attr0:meta ] )* $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $( # [ $attr1:meta ] )* $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? $(,)?
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : public $( , $( $rest:tt )* )?
+    } => {
+        $crate::new_t_group! { @munch [ $marker $owner $cell pub ] $( $( $rest )* )? }
+    };
+    {
+        @munch [ $_marker:tt $owner:tt $cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis marker : $marker_name:ident $( , $( $rest:tt )* )?
     } => {
-        $( # [ $attr2:meta ] )*
-        $crate::new_t_marker_type! {
-            $visibilty2 $marker_name
-        }
-        $( # [ $attr0:meta ] )*
         $crate::new_t_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+            @munch [ ( $( #[$attr] )* $visibilty $marker_name ) $owner $cell $access ]
+            $( $( $rest )* )?
         }
-        $( # [ $attr1:meta ] )*
+    };
+    {
+        @munch [ $marker:tt $_owner:tt $cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis owner : $owner_name:ident $( , $( $rest:tt )* )?
+    } => {
         $crate::new_t_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+            @munch [ $marker ( $( #[$attr] )* $visibilty $owner_name ) $cell $access ]
+            $( $( $rest )* )?
         }
     };
     {
-        $( # [ $attr0:meta ] )* $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $( # [ $attr2:meta ] )* $visibilty2:vis marker : $marker_name:ident ,
-        $( # [ $attr1:meta ] )* $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? $(,)?
+        @munch [ $marker:tt $owner:tt $_cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis cell : $cell_name:ident $( < $cell_type:ident > )?
+        $( , $( $rest:tt )* )?
     } => {
-        $( # [ $attr2:meta ] )*
-        $crate::new_t_marker_type! {
-            $visibilty2 $marker_name
-        }
-        $( # [ $attr0:meta ] )*
         $crate::new_t_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+            @munch [ $marker $owner ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? ) $access ]
+            $( $( $rest )* )?
         }
-        $( # [ $attr1:meta ] )*
+    };
+    {
+        @munch [ $marker:tt $owner:tt $cell:tt $access:tt ]
+    } => {
+        $crate::new_t_group! { @build => $access $marker $owner $cell }
+    };
+    // Short syntax
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis uni
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_t_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+            @build => uni
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
     };
     {
-        $( # [ $attr0:meta ] )* $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $( # [ $attr1:meta ] )* $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? ,
-        $( # [ $attr2:meta ] )* $visibilty2:vis marker : $marker_name:ident $(,)?
+        $( # [ $attr:meta ] )* $visibilty:vis pvt
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
     } => {
-        $( # [ $attr2:meta ] )*
-        $crate::new_t_marker_type! {
-            $visibilty2 $marker_name
+        $crate::new_t_group! {
+            @build => pvt
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
-        $( # [ $attr0:meta ] )*
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pub
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_t_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+            @build => pub
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
-        $( # [ $attr1:meta ] )*
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_t_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+            @build => uni
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
     };
+    {
+        $( $fields:tt )+
+    } => {
+        $crate::new_t_group! { @munch [ ( ) ( ) ( ) uni ] $( $fields )+ }
+    };
 }
 
-/// This macro creates a new uniform [`TLCell`] cell group,
+/// This macro creates a new [`TLCell`] cell group,
 /// without the creation of an inline module.
 /// 
+/// Groups are uniform by default, but a `uni`, `pvt`, or `pub` keyword
+/// after the visibility selects the subcategory instead. The cells of
+/// every group implement the matching [`GetEasyUniOwner`],
+/// [`GetEasyPvtOwner`], or [`GetEasyPubOwner`] `trait`, so they can be
+/// used with the `_` and `[self]` owner sources of [`c_scp`].
+/// 
 /// ```rust
 /// new_tl_group!(OwnerTypeName[MarkerTypeName] => CellTypeName<T>);
 /// 
 /// // Also allows for attributes
 /// new_tl_group!(#[allow(dead_code)] pub OwnerTypeName[MarkerTypeName] => CellTypeName<T>);
 /// 
+/// // Also allows for a subcategory
+/// new_tl_group!(pvt PvtOwnerTypeName[PvtMarkerTypeName] => PvtCellTypeName<T>);
+/// new_tl_group!(pub pub PubOwnerTypeName[PubMarkerTypeName] => PubCellTypeName<T>);
+/// 
 /// // Also allows for `struct`-like syntax, where individual components can
 /// // have different attributes
 /// new_tl_group! {
@@ -1834,132 +3205,422 @@ macro_rules! new_t_group {
 ///     pub owner: OwnerTypeName,
 ///     #[allow(dead_code)] pub cell: CellTypeName<T>
 /// }
+/// 
+/// // The `struct`-like syntax takes the subcategory as an `access` entry,
+/// // which is one of `uniform`, `private`, or `public`
+/// new_tl_group! {
+///     access: private,
+///     marker: PvtMarkerTypeName,
+///     owner: PvtOwnerTypeName,
+///     cell: PvtCellTypeName<T>
+/// }
 /// ```
 /// 
 /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`c_scp`]: ./macro.c_scp.html
+/// [`GetEasyUniOwner`]: ./trait.GetEasyUniOwner.html
+/// [`GetEasyPvtOwner`]: ./trait.GetEasyPvtOwner.html
+/// [`GetEasyPubOwner`]: ./trait.GetEasyPubOwner.html
+#[cfg(feature = "std")]
 #[macro_export]
 macro_rules! new_tl_group {
     {
-        $visibilty:vis $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+        @build => $access:tt
+        ( $( # [ $marker_attr:meta ] )* $marker_visibilty:vis $marker_name:ident )
+        ( $( # [ $owner_attr:meta ] )* $owner_visibilty:vis $owner_name:ident )
+        ( $( # [ $cell_attr:meta ] )* $cell_visibilty:vis $cell_name:ident $( < $cell_type:ident > )? )
     } => {
         $crate::new_tl_marker_type! {
-            $visibilty $marker_name
+            @build => $access ( $( #[$marker_attr] )* $marker_visibilty $marker_name )
         }
         $crate::new_tl_owner_type! {
-            $visibilty $owner_name [ $marker_name ]
+            $( #[$owner_attr] )* $owner_visibilty $owner_name [ $marker_name ]
         }
         $crate::new_tl_cell_type! {
-            $visibilty $cell_name $( < $cell_type > )? [ $marker_name ]
+            $( #[$cell_attr] )* $cell_visibilty $cell_name [ $marker_name ] $( < $cell_type > )?
+            , owner : $owner_name
         }
     };
+    // Struct-like syntax
     {
-        @for_individual => ( $visibilty:vis cell : $cell_name:ident $( < $cell_type:ident > )? [ $marker_name:ident ] )
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : uniform $( , $( $rest:tt )* )?
     } => {
-        $crate::new_tl_cell_type! {
-            $visibilty $cell_name [ $marker_name ] $( < $cell_type > )?
-        }
+        $crate::new_tl_group! { @munch [ $marker $owner $cell uni ] $( $( $rest )* )? }
     };
     {
-        @for_individual => ( $visibilty:vis owner : $owner_name:ident [ $marker_name:ident ] )
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : private $( , $( $rest:tt )* )?
     } => {
-        $crate::new_tl_owner_type! {
-            $visibilty $owner_name [ $marker_name ]
+        $crate::new_tl_group! { @munch [ $marker $owner $cell pvt ] $( $( $rest )* )? }
+    };
+    {
+        @munch [ $marker:tt $owner:tt $cell:tt $_access:tt ]
+        access : public $( , $( $rest:tt )* )?
+    } => {
+        $crate::new_tl_group! { @munch [ $marker $owner $cell pub ] $( $( $rest )* )? }
+    };
+    {
+        @munch [ $_marker:tt $owner:tt $cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis marker : $marker_name:ident $( , $( $rest:tt )* )?
+    } => {
+        $crate::new_tl_group! {
+            @munch [ ( $( #[$attr] )* $visibilty $marker_name ) $owner $cell $access ]
+            $( $( $rest )* )?
         }
     };
     {
-        $visibilty2:vis marker : $marker_name:ident ,
-        $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? $(,)?
+        @munch [ $marker:tt $_owner:tt $cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis owner : $owner_name:ident $( , $( $rest:tt )* )?
     } => {
-        $crate::new_tl_marker_type! {
-            $visibilty2 $marker_name
+        $crate::new_tl_group! {
+            @munch [ $marker ( $( #[$attr] )* $visibilty $owner_name ) $cell $access ]
+            $( $( $rest )* )?
         }
+    };
+    {
+        @munch [ $marker:tt $owner:tt $_cell:tt $access:tt ]
+        $( # [ $attr:meta ] )* $visibilty:vis cell : $cell_name:ident $( < $cell_type:ident > )?
+        $( , $( $rest:tt )* )?
+    } => {
         $crate::new_tl_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+            @munch [ $marker $owner ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? ) $access ]
+            $( $( $rest )* )?
         }
+    };
+    {
+        @munch [ $marker:tt $owner:tt $cell:tt $access:tt ]
+    } => {
+        $crate::new_tl_group! { @build => $access $marker $owner $cell }
+    };
+    // Short syntax
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis uni
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_tl_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+            @build => uni
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
     };
     {
-        $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $visibilty2:vis marker : $marker_name:ident ,
-        $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? $(,)?
+        $( # [ $attr:meta ] )* $visibilty:vis pvt
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
     } => {
-        $crate::new_tl_marker_type! {
-            $visibilty2 $marker_name
+        $crate::new_tl_group! {
+            @build => pvt
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis pub
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_tl_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+            @build => pub
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
+    };
+    {
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
         $crate::new_tl_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+            @build => uni
+            ( $( #[$attr] )* $visibilty $marker_name )
+            ( $( #[$attr] )* $visibilty $owner_name )
+            ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
         }
     };
     {
-        $visibilty0:vis $key0:ident : $value0:ident $( < $cell_type0:ident > )? ,
-        $visibilty1:vis $key1:ident : $value1:ident $( < $cell_type1:ident > )? ,
-        $visibilty2:vis marker : $marker_name:ident $(,)?
+        $( $fields:tt )+
     } => {
-        $crate::new_tl_marker_type! {
-            $visibilty2 $marker_name
+        $crate::new_tl_group! { @munch [ ( ) ( ) ( ) uni ] $( $fields )+ }
+    };
+}
+
+/// This macro creates a new cell group, without the creation of an
+/// inline module, using the cell implementation chosen by its `impl`
+/// argument. This allows the implementation of a group to be changed
+/// without rewriting the rest of the invocation.
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// new_group!(impl = T, FooOwner[FooMarker] => FooCell<T>);
+/// 
+/// // Also allows for a subcategory, attributes and visibility specs
+/// new_group!(impl = TL, access = pub, #[allow(dead_code)] pub BarOwner[BarMarker] => BarCell<T>);
+/// 
+/// // `TCell` and `TLCell` groups also allow for `struct`-like syntax
+/// new_group! {
+///     impl = TL,
+///     access: private,
+///     marker: BazMarker,
+///     owner: BazOwner,
+///     cell: BazCell<T>
+/// }
+/// ```
+/// 
+/// The valid implementations are:
+/// 1. `T`: Routes to [`new_t_group`], and requires the `std` feature.
+/// 2. `TL`: Routes to [`new_tl_group`], and requires the `std` feature.
+/// 3. `Q`: Declares a [`QCell`] owner and cell `type`, and requires the
+///    `alloc` feature.
+/// 4. `L`: Declares an [`LCell`] owner and cell `type`, which both take
+///    an extra `'id` lifetime parameter.
+/// 5. `auto`: Chooses `TL` if the `auto-tl` feature of the crate calling
+///    this macro is enabled, and `T` otherwise, so a whole crate can
+///    switch between them from its `Cargo.toml`. That crate has to declare
+///    `auto-tl = []` under its own `[features]`.
+/// 
+/// [`QCell`] and [`LCell`] groups have no marker, so the marker name is
+/// not declared, and they do not have a subcategory, so the `access`
+/// argument is accepted but has no effect.
+/// 
+/// [`QCell`]: https://docs.rs/qcell/latest/qcell/struct.QCell.html
+/// [`LCell`]: https://docs.rs/qcell/latest/qcell/struct.LCell.html
+/// [`new_t_group`]: ./macro.new_t_group.html
+/// [`new_tl_group`]: ./macro.new_tl_group.html
+#[macro_export]
+macro_rules! new_group {
+    {
+        @for_q_cell => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident ) $cell_type:ident
+    } => {
+        $( #[$attr] )*
+        $visibilty type $cell_name<$cell_type> = $crate::qcell::QCell<$cell_type>;
+    };
+    {
+        @for_q_cell => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident )
+    } => {
+        $( #[$attr] )*
+        $visibilty type $cell_name<T> = $crate::qcell::QCell<T>;
+    };
+    {
+        @for_l_cell => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident ) $cell_type:ident
+    } => {
+        $( #[$attr] )*
+        $visibilty type $cell_name<'id, $cell_type> = $crate::qcell::LCell<'id, $cell_type>;
+    };
+    {
+        @for_l_cell => ( $( # [ $attr:meta ] )* $visibilty:vis $cell_name:ident )
+    } => {
+        $( #[$attr] )*
+        $visibilty type $cell_name<'id, T> = $crate::qcell::LCell<'id, T>;
+    };
+    {
+        impl = auto , $( $rest:tt )+
+    } => {
+        $crate::__auto_impl! { $( $rest )+ }
+    };
+    {
+        impl = T , access = $access:tt ,
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::new_t_group! {
+                @build => $access
+                ( $( #[$attr] )* $visibilty $marker_name )
+                ( $( #[$attr] )* $visibilty $owner_name )
+                ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
+            }
         }
-        $crate::new_tl_group! {
-            @for_individual => ( $visibilty0 $key0 : $value0 $( < $cell_type0 > )? [ $marker_name ] )
+    };
+    {
+        impl = TL , access = $access:tt ,
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::new_tl_group! {
+                @build => $access
+                ( $( #[$attr] )* $visibilty $marker_name )
+                ( $( #[$attr] )* $visibilty $owner_name )
+                ( $( #[$attr] )* $visibilty $cell_name $( < $cell_type > )? )
+            }
         }
-        $crate::new_tl_group! {
-            @for_individual => ( $visibilty1 $key1 : $value1 $( < $cell_type1 > )? [ $marker_name ] )
+    };
+    {
+        impl = T , $( $rest:tt )+
+    } => {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::new_t_group! { $( $rest )+ }
+        }
+    };
+    {
+        impl = TL , $( $rest:tt )+
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::new_tl_group! { $( $rest )+ }
+        }
+    };
+    {
+        impl = Q , access = $_access:tt , $( $rest:tt )+
+    } => {
+        $crate::new_group! { impl = Q , $( $rest )+ }
+    };
+    {
+        impl = Q ,
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $_marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
+        $crate::__require_alloc! {
+            "`QCell` groups require the `alloc` feature of `cell_wrappers`." =>
+            $( #[$attr] )*
+            $visibilty type $owner_name = $crate::qcell::QCellOwner;
+            $crate::new_group! {
+                @for_q_cell => ( $( #[$attr] )* $visibilty $cell_name ) $( $cell_type )?
+            }
+        }
+    };
+    {
+        impl = L , access = $_access:tt , $( $rest:tt )+
+    } => {
+        $crate::new_group! { impl = L , $( $rest )+ }
+    };
+    {
+        impl = L ,
+        $( # [ $attr:meta ] )* $visibilty:vis
+        $owner_name:ident [ $_marker_name:ident ] => $cell_name:ident $( < $cell_type:ident > )?
+    } => {
+        $( #[$attr] )*
+        $visibilty type $owner_name<'id> = $crate::qcell::LCellOwner<'id>;
+        $crate::new_group! {
+            @for_l_cell => ( $( #[$attr] )* $visibilty $cell_name ) $( $cell_type )?
         }
     };
 }
 
 // Panic messages
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn pvt_owner_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a private owner from a mod that cannot provide one."
-    )
+pub fn pvt_owner_unavailable_msg() -> &'static str {
+    "Tried to request a private owner from a mod that cannot provide one."
 }
 
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn uni_owner_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a uniform owner from a mod that cannot provide one."
-    )
+pub fn uni_owner_unavailable_msg() -> &'static str {
+    "Tried to request a uniform owner from a mod that cannot provide one."
 }
 
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn pub_owner_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a public owner from a mod that cannot provide one."
-    )
+pub fn pub_owner_unavailable_msg() -> &'static str {
+    "Tried to request a public owner from a mod that cannot provide one."
 }
 
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn pvt_cell_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a private cell from a mod that cannot provide one."
-    )
+pub fn pvt_cell_unavailable_msg() -> &'static str {
+    "Tried to request a private cell from a mod that cannot provide one."
 }
 
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn uni_cell_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a uniform cell from a mod that cannot provide one."
-    )
+pub fn uni_cell_unavailable_msg() -> &'static str {
+    "Tried to request a uniform cell from a mod that cannot provide one."
 }
 
+#[doc(hidden)]
 #[inline]
-#[allow(dead_code)]
-fn pub_cell_unavailable_msg() -> String {
-    String::from(
-        "Tried to request a public cell from a mod that cannot provide one."
-    )
+pub fn pub_cell_unavailable_msg() -> &'static str {
+    "Tried to request a public cell from a mod that cannot provide one."
+}
+
+// Feature gates for group types. These are resolved against the features
+// of this crate, and not the features of the crate invoking the macros.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_std {
+    { $msg:literal => $( $body:tt )* } => {
+        $( $body )*
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_std {
+    { $msg:literal => $( $body:tt )* } => {
+        compile_error!($msg);
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_alloc {
+    { $msg:literal => $( $body:tt )* } => {
+        $( $body )*
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_alloc {
+    { $msg:literal => $( $body:tt )* } => {
+        compile_error!($msg);
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_alloc {
+    { $( $body:tt )* } => {
+        $( $body )*
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_alloc {
+    { $( $body:tt )* } => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_serde {
+    { $( $body:tt )* } => {
+        $( $body )*
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_serde {
+    { $( $body:tt )* } => {};
+}
+
+// Implementation selection for `new_group!(impl = auto, ...)`. The `cfg`s
+// are expanded into the calling crate, so they are resolved against its
+// own `auto-tl` feature, rather than against a feature of this crate that
+// every other crate in the build would share.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __auto_impl {
+    { $( $rest:tt )+ } => {
+        #[cfg(feature = "auto-tl")]
+        $crate::new_group! { impl = TL , $( $rest )+ }
+        #[cfg(not(feature = "auto-tl"))]
+        $crate::new_group! { impl = T , $( $rest )+ }
+    };
 }
 
 /// This macro can declare entire trees of cell groups at a time.
@@ -1979,6 +3640,22 @@ fn pub_cell_unavailable_msg() -> String {
 /// }
 /// ```
 /// 
+/// Entries inside a cluster are `pub mod` by default, but can also be
+/// given their own visibility and attributes. An entry's attributes are
+/// placed on its own module, so a `cfg` removes the whole entry:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     #[allow(dead_code)]
+///     [pub mod] app::{
+///         #[cfg(feature = "std")] [pub(crate) mod] net: TCellPubGrp,
+///         [mod] io::{disk: TLCellUniGrp},
+///         ui: TLCellUniGrp
+///     };
+/// }
+/// ```
+/// 
 /// The simplest case is just the group name and type:
 /// ```rust
 /// // Creates:
@@ -2002,756 +3679,1469 @@ fn pub_cell_unavailable_msg() -> String {
 /// 3. `TLCellPubGrp`
 /// 4. `TLCellPvtGrp`
 /// 
-/// * `...UniGrp` types are general-purpose, and not intended for any
-/// specific role.
+/// The valid [`QCell`] group types are:
+/// 1. `QCellUniGrp`
+/// 2. `QCellAccGrp`
+/// 3. `QCellPubGrp`
+/// 4. `QCellPvtGrp`
+/// 
+/// The valid [`LCell`] group types are:
+/// 1. `LCellUniGrp`
+/// 2. `LCellAccGrp`
+/// 3. `LCellPubGrp`
+/// 4. `LCellPvtGrp`
+/// 
+/// * `...UniGrp` types are general-purpose, and not intended for any
+///   specific role.
 /// * `...AccGrp` types create a public and private group in one inline module.
 /// * `...PubGrp` types are intended for program-wide access.
 /// * `...PvtGrp` types are intended for internal `struct` method use only.
 /// 
+/// The cell `type`s accept unsized contents, such as `UniCell<dyn Trait>`,
+/// which can be stored behind a `Box` or `Rc`, and borrowed through [`c_scp`].
+/// 
+/// [`TCell`] and [`TLCell`] groups require the `std` feature, and [`QCell`]
+/// groups require the `alloc` feature. [`LCell`] groups are always available.
+/// 
+/// [`QCell`] and [`LCell`] groups have no marker, since their owners are
+/// told apart at runtime or by lifetime, so only the cell and owner `type`s
+/// are declared. A [`QCell`] group's cell constructors also take the owner
+/// that the new cell will belong to, and an [`LCell`] group's owner
+/// constructors take a closure, which receives a freshly-branded owner and
+/// whose result is returned:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] q_grp: QCellUniGrp;
+///     [pub mod] l_grp: LCellUniGrp;
+/// }
+/// 
+/// let mut q_owner = q_grp::new_uniform_owner();
+/// let q_cell = q_grp::new_uniform_cell(&q_owner, 1);
+/// *q_cell.rw(&mut q_owner) += 1;
+/// assert_eq!(*q_cell.ro(&q_owner), 2);
+/// 
+/// let l_value = l_grp::new_uniform_owner(|mut l_owner| {
+///     let l_cell = l_grp::new_uniform_cell(1);
+///     *l_cell.rw(&mut l_owner) += 1;
+///     *l_cell.ro(&l_owner)
+/// });
+/// assert_eq!(l_value, 2);
+/// ```
+///
+/// [`TCell`] and [`TLCell`] group types can also take `const` or `type`
+/// parameters, which turns each marker into a family of markers. Every
+/// instantiation, such as `UniMarker<3>`, is a distinct marker with its
+/// own owner, so the owners of different shards can be held at once:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] shard: TCellUniGrp<const ID: usize>;
+/// }
+/// 
+/// // Creates:
+/// //     self::shard::UniMarker<const ID: usize>
+/// //     self::shard::UniOwner<const ID: usize>
+/// //     self::shard::UniCell<const ID: usize, T>
+/// let first: shard::UniCell<0, i32> = shard::new_uniform_cell(1);
+/// let third: shard::UniCell<3, i32> = shard::new_uniform_cell(2);
+/// 
+/// let first_owner = shard::new_uniform_owner::<0>();
+/// c_scp! {
+///     use shard::UniOwner<3> => (
+///         third => mut cont
+///     ) {
+///         *cont += *first.ro(&first_owner);
+///     }
+/// }
+/// ```
+/// 
+/// Type parameters must be `'static`, and only mark the family, so they
+/// are not stored anywhere.
+/// 
+/// The items of a group can also be renamed, which keeps groups from
+/// colliding when they are brought into one scope with a glob import.
+/// Each subcategory takes `(Marker, Owner, Cell)` names, or `(Owner, Cell)`
+/// names for [`QCell`] and [`LCell`] groups, and any subcategory that is
+/// left out keeps its usual names. The shared cell aliases described below
+/// can be renamed too, by appending `RcCell, ArcCell, WeakCell` names to
+/// the tuple:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] audio: TCellAccGrp as {
+///         pub: (MixerMarker, MixerOwner, MixerCell),
+///         pvt: (DeviceMarker, DeviceOwner, DeviceCell)
+///     };
+///     [pub mod] video: TCellUniGrp as { uni: (FrameMarker, FrameOwner, FrameCell) };
+/// }
+/// use audio::*;
+/// use video::*;
+/// 
+/// let mixer_cell: MixerCell<i32> = MixerCell::new(1);
+/// c_scp! {
+///     use MixerOwner => (
+///         mixer_cell => *cont
+///     ) {
+///         assert_eq!(cont, 1);
+///     }
+/// }
+/// ```
+/// 
+/// The functions of a group, such as `new_public_cell`, keep their names,
+/// so they should still be called through the module of their group.
+/// Renames other than `pvt`, `uni` and `pub` are rejected when compiling:
+/// 
+/// ```rust,compile_fail
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] video: TCellUniGrp as { unii: (FrameMarker, FrameOwner, FrameCell) };
+/// }
+/// ```
+/// 
+/// A `with` clause can follow the group type, or its renames, to select
+/// options for the group. With `cells: newtype`, the cells of a [`TCell`] or
+/// [`TLCell`] group are declared as `#[repr(transparent)]` wrappers, instead
+/// of `type` aliases, so that `trait`s can be implemented on them:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] settings: TCellUniGrp with { cells: newtype };
+/// }
+/// 
+/// impl<T: std::fmt::Display> std::fmt::Display for settings::UniCell<T> {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}", self.ro(&settings::new_uniform_owner()))
+///     }
+/// }
+/// 
+/// let volume: settings::UniCell<u8> = 7.into();
+/// assert_eq!(volume.to_string(), "7");
+/// ```
+/// 
+/// These cells forward `new`, `ro`, `rw`, `get_mut` and `into_inner` to the
+/// wrapped cell, which is also available through `as_inner`, and implement
+/// `From<T>`, along with `Default` when `T` does. They do not implement
+/// `Deref`, and [`c_scp`] uses them in the same way as any other cell.
+/// 
+/// With `owners: newtype`, the owners of a [`TCell`] or [`TLCell`] group are
+/// declared as wrappers, which carry the path of their group module as
+/// `GROUP`, print it with `Debug`, and run the hooks of their group whenever
+/// one of them is created or dropped. Hooks are provided by a type which
+/// implements [`OwnerHooks`], given as `hooks: Type`, or `hooks: (path::Type)`:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// pub struct Meter;
+/// 
+/// impl OwnerHooks for Meter {
+///     fn on_acquire(group: &'static str) {
+///         println!("{} acquired", group);
+///     }
+///     fn on_release(group: &'static str) {
+///         println!("{} released", group);
+///     }
+/// }
+/// 
+/// def_cells! {
+///     [pub mod] mixer: TCellUniGrp with { owners: newtype, hooks: (super::Meter) };
+/// }
+/// 
+/// # fn main() {
+/// let level = mixer::new_uniform_cell(0);
+/// c_scp! {
+///     use mixer::UniOwner => (
+///         level => mut cont
+///     ) {
+///         *cont += 1;
+///     }
+/// }
+/// assert!(mixer::UniOwner::GROUP.ends_with("mixer"));
+/// # }
+/// ```
+/// 
+/// These owners dereference to the owner that they wrap, so they can be
+/// passed to any cell of their group. A group's `hooks` type is named from
+/// inside of the group's module, so it may need a `super::` path.
+/// 
+/// Options other than `cells`, `owners` and `hooks` are rejected when
+/// compiling, so a misspelled option does not quietly leave the defaults:
+/// 
+/// ```rust,compile_fail
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] settings: TCellUniGrp with { cell: newtype };
+/// }
+/// ```
+/// 
+/// ```rust,compile_fail
+/// # use cell_wrappers::*;
+/// def_cells! {
+///     [pub mod] mixer: TCellUniGrp with { owner: newtype };
+/// }
+/// ```
+/// 
+/// Every group also declares `Rc`, `Arc` and `Weak` aliases of its cells,
+/// such as `RcUniCell<T>`, `ArcPubCell<T>` and `WeakUniCell<T>`, along with
+/// `new_rc_*_cell` and `new_arc_*_cell` constructors. `WeakUniCell<T>` is
+/// the `Weak` of an `RcUniCell<T>`. These require the `alloc` feature, and
+/// a weak cell can be upgraded for a scope with [`c_scp`]'s `weak` form:
+/// 
+/// ```rust
+/// # use cell_wrappers::*;
+/// # use std::rc::Rc;
+/// def_cells! {
+///     [pub mod] tree: TCellUniGrp;
+/// }
+/// 
+/// let node: tree::RcUniCell<u32> = tree::new_rc_uniform_cell(1);
+/// let parent: tree::WeakUniCell<u32> = Rc::downgrade(&node);
+/// c_scp! {
+///     use _ => (
+///         weak parent => mut cont
+///     ) {
+///         *cont += 1;
+///     }
+/// }
+/// ```
+/// 
 /// If you need dummy `structs` for your linter to detect and validate
 /// group types, you might want to `use` the `cell_grp_types` module.
 /// 
 /// [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
 /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+/// [`QCell`]: https://docs.rs/qcell/latest/qcell/struct.QCell.html
+/// [`LCell`]: https://docs.rs/qcell/latest/qcell/struct.LCell.html
+/// [`c_scp`]: ./macro.c_scp.html
+/// [`OwnerHooks`]: ./trait.OwnerHooks.html
 #[macro_export]
 macro_rules! def_cells {
-    // Uniform group
+    // Cell group
+    {
+        @for_group => &:$attrs:tt:& -> $cell_impl:ident , $renames:tt ,
+        $pvt_slot:tt , $uni_slot:tt , $pub_slot:tt
+    } => {
+        #[inline]
+        #[allow(dead_code)]
+        pub fn get_cell_impl() -> $crate::CellImpl {
+            $crate::CellImpl::$cell_impl
+        }
+
+        $crate::def_cells! {
+            @for_renamed_slot pvt $renames => &:$attrs:& -> $pvt_slot ,
+            (
+                has_private_access , new_private_owner , new_private_cell ,
+                new_rc_private_cell , new_arc_private_cell ,
+                pvt_owner_unavailable_msg , pvt_cell_unavailable_msg
+            )
+        }
+        $crate::def_cells! {
+            @for_renamed_slot uni $renames => &:$attrs:& -> $uni_slot ,
+            (
+                has_uniform_access , new_uniform_owner , new_uniform_cell ,
+                new_rc_uniform_cell , new_arc_uniform_cell ,
+                uni_owner_unavailable_msg , uni_cell_unavailable_msg
+            )
+        }
+        $crate::def_cells! {
+            @for_renamed_slot pub $renames => &:$attrs:& -> $pub_slot ,
+            (
+                has_public_access , new_public_owner , new_public_cell ,
+                new_rc_public_cell , new_arc_public_cell ,
+                pub_owner_unavailable_msg , pub_cell_unavailable_msg
+            )
+        }
+    };
+
+    // Custom item names
+    {
+        @for_renamed_slot $key:ident { } => &:$attrs:tt:& ->
+        ( $slot:ident $names:tt ) , $fn_names:tt
+    } => {
+        $crate::def_cells! { @for_slot $slot => &:$attrs:& -> $names , $fn_names }
+    };
     {
-        @for_uniform_group => &:($( #[$attr:meta] )*):& ->
+        @for_renamed_slot pvt { pvt : $new_names:tt $( , $( $rest:tt )* )? } => &:$attrs:tt:& ->
+        ( $slot:ident $names:tt ) , $fn_names:tt
+    } => {
+        $crate::def_cells! { @with_names pvt $slot $new_names $names => &:$attrs:& -> $fn_names }
+    };
+    {
+        @for_renamed_slot uni { uni : $new_names:tt $( , $( $rest:tt )* )? } => &:$attrs:tt:& ->
+        ( $slot:ident $names:tt ) , $fn_names:tt
+    } => {
+        $crate::def_cells! { @with_names uni $slot $new_names $names => &:$attrs:& -> $fn_names }
+    };
+    {
+        @for_renamed_slot pub { pub : $new_names:tt $( , $( $rest:tt )* )? } => &:$attrs:tt:& ->
+        ( $slot:ident $names:tt ) , $fn_names:tt
+    } => {
+        $crate::def_cells! { @with_names pub $slot $new_names $names => &:$attrs:& -> $fn_names }
+    };
+    {
+        @for_renamed_slot $key:ident { pvt : $_skipped:tt $( , $( $rest:tt )* )? } =>
+        &:$attrs:tt:& -> $slot:tt , $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_renamed_slot $key { $( $( $rest )* )? } => &:$attrs:& -> $slot , $fn_names
+        }
+    };
+    {
+        @for_renamed_slot $key:ident { uni : $_skipped:tt $( , $( $rest:tt )* )? } =>
+        &:$attrs:tt:& -> $slot:tt , $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_renamed_slot $key { $( $( $rest )* )? } => &:$attrs:& -> $slot , $fn_names
+        }
+    };
+    {
+        @for_renamed_slot $key:ident { pub : $_skipped:tt $( , $( $rest:tt )* )? } =>
+        &:$attrs:tt:& -> $slot:tt , $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_renamed_slot $key { $( $( $rest )* )? } => &:$attrs:& -> $slot , $fn_names
+        }
+    };
+    {
+        @for_renamed_slot $_key:ident { $other:tt : $( $_rest:tt )* } => $( $_details:tt )*
+    } => {
+        compile_error!(concat!(
+            "unknown rename `", stringify!($other), "`; expected `pvt`, `uni` or `pub`"
+        ));
+    };
+    {
+        @with_names $key:ident marked ( $marker_name:ident , $owner_name:ident , $cell_name:ident )
+        ( $_marker:ident , $_cell:ident , $_owner:ident , $( $details:tt )* ) =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_slot marked => &:$attrs:& ->
+            ( $marker_name , $cell_name , $owner_name , $( $details )* ) , $fn_names
+        }
+    };
+    {
+        @with_names $key:ident marked (
+            $marker_name:ident , $owner_name:ident , $cell_name:ident ,
+            $rc_name:ident , $arc_name:ident , $weak_name:ident
+        )
+        ( $_marker:ident , $_cell:ident , $_owner:ident , $_shared:tt , $( $details:tt )* ) =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_slot marked => &:$attrs:& -> (
+                $marker_name , $cell_name , $owner_name ,
+                ( $rc_name , $arc_name , $weak_name ) , $( $details )*
+            ) , $fn_names
+        }
+    };
+    {
+        @with_names $key:ident $slot:ident ( $owner_name:ident , $cell_name:ident )
+        ( $_cell:ident , $_owner:ident , $shared_names:tt ) =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_slot $slot => &:$attrs:& -> ( $cell_name , $owner_name , $shared_names ) , $fn_names
+        }
+    };
+    {
+        @with_names $key:ident $slot:ident (
+            $owner_name:ident , $cell_name:ident ,
+            $rc_name:ident , $arc_name:ident , $weak_name:ident
+        )
+        ( $_cell:ident , $_owner:ident , $_shared:tt ) =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_slot $slot => &:$attrs:& ->
+            ( $cell_name , $owner_name , ( $rc_name , $arc_name , $weak_name ) ) , $fn_names
+        }
+    };
+    {
+        @with_names $key:ident absent $new_names:tt $names:tt =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        compile_error!(concat!(
+            "This group type has no `", stringify!($key), "` items to rename."
+        ));
+    };
+    {
+        @with_names $key:ident marked $new_names:tt $names:tt =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        compile_error!(concat!(
+            "The `", stringify!($key), "` items of `TCell` and `TLCell` groups ",
+            "are renamed with `(Marker, Owner, Cell)`, or with ",
+            "`(Marker, Owner, Cell, RcCell, ArcCell, WeakCell)`."
+        ));
+    };
+    {
+        @with_names $key:ident $slot:ident $new_names:tt $names:tt =>
+        &:$attrs:tt:& -> $fn_names:tt
+    } => {
+        compile_error!(concat!(
+            "The `", stringify!($key), "` items of `QCell` and `LCell` groups ",
+            "are renamed with `(Owner, Cell)`, or with ",
+            "`(Owner, Cell, RcCell, ArcCell, WeakCell)`."
+        ));
+    };
+
+    // Subcategories -> TCell / TLCell
+    {
+        @for_slot marked => &:($( #[$attr:meta] )*):& ->
         (
+            $marker_name:ident , $cell_name:ident , $owner_name:ident , $shared_names:tt ,
             $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $_marker_pub_type:ident ,
-            $owner_pvt_type:ident , $_owner_pub_type:ident ,
-            $cell_pvt_type:ident , $_cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $_pub_impl_type:ident
-        )
+            $impl_type:ident , $access_type:ident , [ ] , $options:tt
+        ) ,
+        $fn_names:tt
     } => {
-        use $crate::*;
         $( #[$attr] )*
-        pub struct UniMarker ;
-        $( #[$attr] )*
-        pub type UniCell<T> = qcell::$cell_type<UniMarker,T> ;
-        $( #[$attr] )*
-        pub type UniOwner = qcell::$owner_type<UniMarker> ;
-
-        impl $crate::IsGTUniAccess for UniMarker {}
-        impl $crate::IsGTMarker for UniMarker {}
-        impl $crate::$impl_type for UniMarker {}
-        impl $crate::$pvt_impl_type for UniMarker {}
-        impl $crate::$marker_impl_type for UniMarker {}
-        impl $crate::$marker_pvt_type for UniMarker {}
+        pub struct $marker_name ;
 
-        impl<T> $crate::IsGTUniAccess for UniCell<T> {}
-        impl<T> $crate::$pvt_impl_type for UniCell<T> {}
-        impl<T> $crate::$cell_pvt_type for UniCell<T> {}
-
-        impl $crate::IsGTUniAccess for UniOwner {}
-        impl $crate::$pvt_impl_type for UniOwner {}
-        impl $crate::$owner_pvt_type for UniOwner {}
+        $crate::def_cells! {
+            @for_marked => &:( $( #[$attr] )* ):& -> (
+                $marker_name , $cell_name , $owner_name , $shared_names ,
+                $cell_type , $owner_type ,
+                $impl_type , $access_type
+            ) , $fn_names , $options , [ ] [ ] [ ]
+        }
+    };
 
-        impl<T> $crate::GetEasyUniOwner for UniCell<T> {
-            type OwnerType = UniOwner;
+    // Subcategories -> TCell / TLCell marker families
+    {
+        @for_slot marked => &:$attrs:tt:& ->
+        (
+            $marker_name:ident , $cell_name:ident , $owner_name:ident , $shared_names:tt ,
+            $cell_type:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident , [ ( $( $params:tt )* ) ] , $options:tt
+        ) ,
+        $fn_names:tt
+    } => {
+        $crate::def_cells! {
+            @for_params => &:$attrs:& -> (
+                $marker_name , $cell_name , $owner_name , $shared_names ,
+                $cell_type , $owner_type ,
+                $impl_type , $access_type
+            ) , $fn_names , $options , [ ] [ ] [ ] [ ] $( $params )*
+        }
+    };
+    {
+        @for_params => &:$attrs:tt:& -> $names:tt , $fn_names:tt , $options:tt ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ] [ $( $phantom:tt )* ]
+        const $param:ident : $param_type:ty $( , $( $rest:tt )* )?
+    } => {
+        $crate::def_cells! {
+            @for_params => &:$attrs:& -> $names , $fn_names , $options ,
+            [ $( $decl )* const $param : $param_type , ]
+            [ $( $impl_decl )* const $param : $param_type , ]
+            [ $( $args )* $param , ]
+            [ $( $phantom )* ]
+            $( $( $rest )* )?
+        }
+    };
+    {
+        @for_params => &:$attrs:tt:& -> $names:tt , $fn_names:tt , $options:tt ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ] [ $( $phantom:tt )* ]
+        $param:ident $( , $( $rest:tt )* )?
+    } => {
+        $crate::def_cells! {
+            @for_params => &:$attrs:& -> $names , $fn_names , $options ,
+            [ $( $decl )* $param , ]
+            [ $( $impl_decl )* $param : 'static , ]
+            [ $( $args )* $param , ]
+            [ $( $phantom )* fn() -> $param , ]
+            $( $( $rest )* )?
+        }
+    };
+    {
+        @for_params => &:($( #[$attr:meta] )*):& ->
+        (
+            $marker_name:ident , $cell_name:ident , $owner_name:ident , $shared_names:tt ,
+            $cell_type:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        $fn_names:tt , $options:tt ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ] [ $( $phantom:tt )* ]
+    } => {
+        $( #[$attr] )*
+        pub struct $marker_name< $( $decl )* >( ::core::marker::PhantomData<( $( $phantom )* )> ) ;
 
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
+        $crate::def_cells! {
+            @for_marked => &:( $( #[$attr] )* ):& -> (
+                $marker_name , $cell_name , $owner_name , $shared_names ,
+                $cell_type , $owner_type ,
+                $impl_type , $access_type
+            ) , $fn_names , $options ,
+            [ $( $decl )* ] [ $( $impl_decl )* ] [ $( $args )* ]
+        }
+    };
 
-            fn get_matching_owner_from(&self, src : & impl $crate::GetUniOwner<Self::OwnerType>) -> Self::OwnerType {
-                src.get_uniform_owner()
-            }
+    // Items of a marked subcategory, for a marker with any generics
+    {
+        @for_marked => &:($( #[$attr:meta] )*):& ->
+        (
+            $marker_name:ident , $cell_name:ident , $owner_name:ident ,
+            ( $rc_name:ident , $arc_name:ident , $weak_name:ident ) ,
+            $cell_type:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        (
+            $has_access:ident , $new_owner:ident , $new_cell:ident ,
+            $new_rc_cell:ident , $new_arc_cell:ident ,
+            $_owner_msg:ident , $_cell_msg:ident
+        ) ,
+        $options:tt ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
+    } => {
+        $crate::def_cells! {
+            @for_owner_option [ alias ] [ ] $options => &:( $( #[$attr] )* ):& -> (
+                $marker_name , $owner_name , $owner_type ,
+                $impl_type , $access_type
+            ) , [ $( $decl )* ] [ $( $impl_decl )* ] [ $( $args )* ]
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn get_cell_impl() -> $crate::CellImpl {
-            UniMarker::get_cell_impl()
+        $crate::def_cells! {
+            @for_cell_option [ alias ] $options => &:( $( #[$attr] )* ):& -> (
+                $marker_name , $cell_name , $cell_type , $owner_type ,
+                $impl_type , $access_type
+            ) , [ $( $decl )* ] [ $( $impl_decl )* ] [ $( $args )* ]
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn has_private_access() -> bool {
-            false
+        impl< $( $impl_decl )* > $crate::IsGTMarker for $marker_name< $( $args )* > {}
+        impl< $( $impl_decl )* > $crate::$impl_type for $marker_name< $( $args )* > {}
+        impl< $( $impl_decl )* > $crate::$access_type for $marker_name< $( $args )* > {}
+
+        impl< $( $impl_decl )* > $crate::CellGroup for $marker_name< $( $args )* > {
+            type Owner = $owner_name< $( $args )* >;
+
+            #[inline]
+            fn new_owner() -> Self::Owner {
+                <$owner_name< $( $args )* >>::new()
+            }
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_uniform_access() -> bool {
+        pub fn $has_access() -> bool {
             true
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_public_access() -> bool {
-            false
+        pub fn $new_owner< $( $impl_decl )* >() -> $owner_name< $( $args )* > {
+            <$owner_name< $( $args )* >>::new()
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_private_owner() -> ! {
-            panic!("{}", $crate::pvt_owner_unavailable_msg())
+        pub fn $new_cell< $( $impl_decl )* T >(item: T) -> $cell_name< $( $args )* T > {
+            <$cell_name< $( $args )* T >>::new(item)
         }
 
-        #[inline]
+        $( #[$attr] )*
         #[allow(dead_code)]
-        pub fn new_uniform_owner() -> UniOwner {
-            UniOwner::new()
-        }
-
-        #[inline]
+        pub type $rc_name< $( $decl )* T > = $crate::__shared::Rc<$cell_name< $( $args )* T >> ;
+        $( #[$attr] )*
         #[allow(dead_code)]
-        pub fn new_public_owner() -> ! {
-            panic!("{}", $crate::pub_owner_unavailable_msg())
-        }
-
-        #[inline]
+        pub type $arc_name< $( $decl )* T > = $crate::__shared::Arc<$cell_name< $( $args )* T >> ;
+        $( #[$attr] )*
         #[allow(dead_code)]
-        pub fn new_private_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::uni_cell_unavailable_msg())
-        }
+        pub type $weak_name< $( $decl )* T > = $crate::__shared::Weak<$cell_name< $( $args )* T >> ;
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_uniform_cell<T>(item: T) -> UniCell<T> {
-            UniCell::new(item)
+        pub fn $new_rc_cell< $( $impl_decl )* T >(item: T) -> $rc_name< $( $args )* T > {
+            $crate::__shared::Rc::new(<$cell_name< $( $args )* T >>::new(item))
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_public_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::pub_cell_unavailable_msg())
+        pub fn $new_arc_cell< $( $impl_decl )* T >(item: T) -> $arc_name< $( $args )* T > {
+            $crate::__shared::Arc::new(<$cell_name< $( $args )* T >>::new(item))
         }
     };
 
-    // Access group
+    // Group options -> Cells
+    {
+        @for_cell_option [ $cell_kind:ident ] { } => $( $cell_info:tt )*
+    } => {
+        $crate::def_cells! { @for_cell $cell_kind => $( $cell_info )* }
+    };
     {
-        @for_access_group => &:($( #[$attr:meta] )*):& ->
+        @for_cell_option [ $_kind:ident ] { cells : $cell_kind:ident $( , $( $rest:tt )* )? } =>
+        $( $cell_info:tt )*
+    } => {
+        $crate::def_cells! { @for_cell_option [ $cell_kind ] { $( $( $rest )* )? } => $( $cell_info )* }
+    };
+    {
+        @for_cell_option $cell_kind:tt { owners : $_value:tt $( , $( $rest:tt )* )? } => $( $cell_info:tt )*
+    } => {
+        $crate::def_cells! { @for_cell_option $cell_kind { $( $( $rest )* )? } => $( $cell_info )* }
+    };
+    {
+        @for_cell_option $cell_kind:tt { hooks : $_value:tt $( , $( $rest:tt )* )? } => $( $cell_info:tt )*
+    } => {
+        $crate::def_cells! { @for_cell_option $cell_kind { $( $( $rest )* )? } => $( $cell_info )* }
+    };
+    {
+        @for_cell_option $_kind:tt { $option:ident : $( $_rest:tt )* } => $( $_cell_info:tt )*
+    } => {
+        compile_error!(concat!(
+            "unknown group option `", stringify!($option), "`; expected `cells`, `owners` or `hooks`"
+        ));
+    };
+    {
+        @for_cell alias => &:($( #[$attr:meta] )*):& ->
         (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $owner_pub_type:ident ,
-            $cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $pub_impl_type:ident
-        )
+            $marker_name:ident , $cell_name:ident , $cell_type:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
     } => {
-        use $crate::*;
         $( #[$attr] )*
-        pub struct PubMarker;
-        $( #[$attr] )*
-        pub type PubCell<T> = qcell::$cell_type<self::PubMarker, T>;
+        pub type $cell_name< $( $decl )* T > =
+            $crate::qcell::$cell_type<$marker_name< $( $args )* >, T> ;
+    };
+    {
+        @for_cell newtype => &:($( #[$attr:meta] )*):& ->
+        (
+            $marker_name:ident , $cell_name:ident , $cell_type:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
+    } => {
         $( #[$attr] )*
-        pub type PubOwner = qcell::$owner_type<self::PubMarker>;
+        #[repr(transparent)]
+        pub struct $cell_name< $( $impl_decl )* T : ?Sized >(
+            $crate::qcell::$cell_type<$marker_name< $( $args )* >, T>
+        ) ;
 
-        impl $crate::IsGTPubAccess for PubMarker {}
-        impl $crate::IsGTMarker for PubMarker {}
-        impl $crate::$impl_type for PubMarker {}
-        impl $crate::$pub_impl_type for PubMarker {}
-        impl $crate::$marker_impl_type for PubMarker {}
-        impl $crate::$marker_pub_type for PubMarker {}
+        #[allow(dead_code)]
+        impl< $( $impl_decl )* T > $cell_name< $( $args )* T > {
+            /// Creates a new cell, containing `value`.
+            #[inline]
+            pub fn new(value: T) -> Self {
+                Self($crate::qcell::$cell_type::new(value))
+            }
 
-        impl<T> $crate::IsGTPubAccess for PubCell<T> {}
-        impl<T> $crate::$pub_impl_type for PubCell<T> {}
-        impl<T> $crate::$cell_pub_type for PubCell<T> {}
+            /// Consumes the cell, and returns its contents.
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.0.into_inner()
+            }
+        }
 
-        impl $crate::IsGTPubAccess for PubOwner {}
-        impl $crate::$pub_impl_type for PubOwner {}
-        impl $crate::$owner_pub_type for PubOwner {}
+        #[allow(dead_code)]
+        impl< $( $impl_decl )* T : ?Sized > $cell_name< $( $args )* T > {
+            /// Borrows the contents immutably, with a borrow of the owner.
+            #[inline]
+            pub fn ro<'a>(
+                &'a self,
+                owner: &'a $crate::qcell::$owner_type<$marker_name< $( $args )* >>
+            ) -> &'a T {
+                self.0.ro(owner)
+            }
 
-        impl<T> $crate::GetEasyPubOwner for PubCell<T> {
-            type OwnerType = PubOwner;
+            /// Borrows the contents mutably, with a mutable borrow of the owner.
+            #[inline]
+            pub fn rw<'a>(
+                &'a self,
+                owner: &'a mut $crate::qcell::$owner_type<$marker_name< $( $args )* >>
+            ) -> &'a mut T {
+                self.0.rw(owner)
+            }
 
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
+            /// Borrows the contents mutably, without an owner, since the
+            /// cell itself is already borrowed mutably.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut T {
+                self.0.get_mut()
             }
 
-            fn get_matching_owner_from(&self, src : & impl $crate::GetPubOwner<Self::OwnerType>) -> Self::OwnerType {
-                src.get_public_owner()
+            /// Gets the wrapped cell, for any operations that are not forwarded.
+            #[inline]
+            pub fn as_inner(&self) -> &$crate::qcell::$cell_type<$marker_name< $( $args )* >, T> {
+                &self.0
             }
         }
-    
-        $( #[$attr] )*
-        pub struct PvtMarker;
-        $( #[$attr] )*
-        pub type PvtCell<T> = qcell::$cell_type<self::PvtMarker, T>;
-        $( #[$attr] )*
-        pub type PvtOwner = qcell::$owner_type<self::PvtMarker>;
 
-        impl $crate::IsGTPvtAccess for PvtMarker {}
-        impl $crate::IsGTMarker for PvtMarker {}
-        impl $crate::$impl_type for PvtMarker {}
-        impl $crate::$pvt_impl_type for PvtMarker {}
-        impl $crate::$marker_impl_type for PvtMarker {}
-        impl $crate::$marker_pvt_type for PvtMarker {}
-
-        impl<T> $crate::IsGTPvtAccess for PvtCell<T> {}
-        impl<T> $crate::$pvt_impl_type for PvtCell<T> {}
-        impl<T> $crate::$cell_pvt_type for PvtCell<T> {}
+        impl< $( $impl_decl )* T > ::core::convert::From<T> for $cell_name< $( $args )* T > {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self::new(value)
+            }
+        }
 
-        impl $crate::IsGTPvtAccess for PvtOwner {}
-        impl $crate::$pvt_impl_type for PvtOwner {}
-        impl $crate::$owner_pvt_type for PvtOwner {}
+        impl< $( $impl_decl )* T : ::core::default::Default > ::core::default::Default
+        for $cell_name< $( $args )* T > {
+            #[inline]
+            fn default() -> Self {
+                Self::new(T::default())
+            }
+        }
 
-        impl<T> $crate::GetEasyPvtOwner for PvtCell<T> {
-            type OwnerType = PvtOwner;
+        impl< $( $impl_decl )* T : ?Sized > $crate::OwnedCell for $cell_name< $( $args )* T > {
+            type Owner = $crate::qcell::$owner_type<$marker_name< $( $args )* >>;
+            type Value = T;
 
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
+            #[inline]
+            fn ro<'a>(&'a self, owner: &'a Self::Owner) -> &'a T {
+                self.0.ro(owner)
             }
 
-            fn get_matching_owner_from(&self, src : & impl $crate::GetPvtOwner<Self::OwnerType>) -> Self::OwnerType {
-                src.get_private_owner()
+            #[inline]
+            fn rw<'a>(&'a self, owner: &'a mut Self::Owner) -> &'a mut T {
+                self.0.rw(owner)
             }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn get_cell_impl() -> $crate::CellImpl {
-            PvtMarker::get_cell_impl()
+        $crate::__with_serde! {
+            impl< $( $impl_decl )* T > $crate::cell_serde::BuildCell for $cell_name< $( $args )* T > {
+                #[inline]
+                fn from_value(value: T) -> Self {
+                    Self::new(value)
+                }
+            }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn has_private_access() -> bool {
-            true
+        impl< $( $impl_decl )* T : ?Sized > $crate::IsGTCell for $cell_name< $( $args )* T > {}
+        impl< $( $impl_decl )* T : ?Sized > $crate::$impl_type for $cell_name< $( $args )* T > {}
+        impl< $( $impl_decl )* T : ?Sized > $crate::$access_type for $cell_name< $( $args )* T > {}
+
+        $crate::def_cells! {
+            @for_get_easy $access_type => ( $cell_name , $marker_name ) ,
+            [ $( $impl_decl )* ] [ $( $args )* ]
         }
+    };
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn has_uniform_access() -> bool {
-            false
+    // Group options -> Owners
+    {
+        @for_owner_option [ $_kind:ident ] $hooks:tt { owners : $owner_kind:ident $( , $( $rest:tt )* )? } =>
+        $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! {
+            @for_owner_option [ $owner_kind ] $hooks { $( $( $rest )* )? } => $( $owner_info )*
         }
+    };
+    {
+        @for_owner_option $owner_kind:tt [ $( $_hooks:tt )? ] { hooks : $hooks:tt $( , $( $rest:tt )* )? } =>
+        $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! {
+            @for_owner_option $owner_kind [ $hooks ] { $( $( $rest )* )? } => $( $owner_info )*
+        }
+    };
+    {
+        @for_owner_option $owner_kind:tt $hooks:tt { cells : $_value:tt $( , $( $rest:tt )* )? } =>
+        $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! {
+            @for_owner_option $owner_kind $hooks { $( $( $rest )* )? } => $( $owner_info )*
+        }
+    };
+    {
+        @for_owner_option $_kind:tt $_hooks:tt { $option:ident : $( $_rest:tt )* } =>
+        $( $_owner_info:tt )*
+    } => {
+        compile_error!(concat!(
+            "unknown group option `", stringify!($option), "`; expected `cells`, `owners` or `hooks`"
+        ));
+    };
+    {
+        @for_owner_option [ alias ] [ ] { } => $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! { @for_owner alias [ ( ) ] => $( $owner_info )* }
+    };
+    {
+        @for_owner_option [ alias ] [ $hooks:tt ] { } => $( $owner_info:tt )*
+    } => {
+        compile_error!("Owner `hooks` require `owners: newtype`.");
+    };
+    {
+        @for_owner_option [ $owner_kind:ident ] [ $( ( ) )? ] { } => $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! { @for_owner $owner_kind [ ( ) ] => $( $owner_info )* }
+    };
+    {
+        @for_owner_option [ $owner_kind:ident ] [ ( $( $hooks:tt )+ ) ] { } => $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! { @for_owner $owner_kind [ $( $hooks )+ ] => $( $owner_info )* }
+    };
+    {
+        @for_owner_option [ $owner_kind:ident ] [ $hooks:ident ] { } => $( $owner_info:tt )*
+    } => {
+        $crate::def_cells! { @for_owner $owner_kind [ $hooks ] => $( $owner_info )* }
+    };
+    {
+        @for_owner alias $hooks:tt => &:($( #[$attr:meta] )*):& ->
+        (
+            $marker_name:ident , $owner_name:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
+    } => {
+        $( #[$attr] )*
+        pub type $owner_name< $( $decl )* > =
+            $crate::qcell::$owner_type<$marker_name< $( $args )* >> ;
+    };
+    {
+        @for_owner newtype [ $( $hooks:tt )+ ] => &:($( #[$attr:meta] )*):& ->
+        (
+            $marker_name:ident , $owner_name:ident , $owner_type:ident ,
+            $impl_type:ident , $access_type:ident
+        ) ,
+        [ $( $decl:tt )* ] [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
+    } => {
+        $( #[$attr] )*
+        pub struct $owner_name< $( $impl_decl )* >(
+            $crate::qcell::$owner_type<$marker_name< $( $args )* >>
+        ) ;
 
-        #[inline]
         #[allow(dead_code)]
-        pub fn has_public_access() -> bool {
-            true
-        }
+        impl< $( $impl_decl )* > $owner_name< $( $args )* > {
+            /// The path of the group which this owner belongs to.
+            pub const GROUP: &'static str = ::core::module_path!();
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_private_owner() -> PvtOwner {
-            PvtOwner::new()
+            /// Creates the owner, and then runs the `on_acquire` hook of its group.
+            #[inline]
+            pub fn new() -> Self {
+                let owner = Self($crate::qcell::$owner_type::new());
+                <$( $hooks )+ as $crate::OwnerHooks>::on_acquire(Self::GROUP);
+                owner
+            }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_uniform_owner() -> ! {
-            panic!("{}", $crate::uni_owner_unavailable_msg())
+        impl< $( $impl_decl )* > ::core::ops::Drop for $owner_name< $( $args )* > {
+            #[inline]
+            fn drop(&mut self) {
+                <$( $hooks )+ as $crate::OwnerHooks>::on_release(Self::GROUP);
+            }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_public_owner() -> PubOwner {
-            PubOwner::new()
-        }
+        impl< $( $impl_decl )* > ::core::ops::Deref for $owner_name< $( $args )* > {
+            type Target = $crate::qcell::$owner_type<$marker_name< $( $args )* >>;
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_private_cell<T>(item: T) -> PvtCell<T> {
-            PvtCell::new(item)
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_uniform_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::uni_cell_unavailable_msg())
+        impl< $( $impl_decl )* > ::core::ops::DerefMut for $owner_name< $( $args )* > {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_public_cell<T>(item: T) -> PubCell<T> {
-            PubCell::new(item)
+        impl< $( $impl_decl )* > ::core::fmt::Debug for $owner_name< $( $args )* > {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(::core::stringify!($owner_name))
+                    .field("group", &Self::GROUP)
+                    .finish()
+            }
         }
-    };
-
-    // Public group
-    {
-        @for_public_group => &:($( #[$attr:meta] )*):& ->
-        (
-            $cell_type:ident , $owner_type:ident ,
-            $_marker_pvt_type:ident , $marker_pub_type:ident ,
-            $_owner_pvt_type:ident , $owner_pub_type:ident ,
-            $_cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $_pvt_impl_type:ident , $pub_impl_type:ident
-        )
-    } => {
-        use $crate::*;
-        pub struct PubMarker;
-        $( #[$attr] )*
-        pub type PubCell<T> = qcell::$cell_type<self::PubMarker, T>;
-        $( #[$attr] )*
-        pub type PubOwner = qcell::$owner_type<self::PubMarker>;
 
-        impl $crate::IsGTPubAccess for PubMarker {}
-        impl $crate::IsGTMarker for PubMarker {}
-        impl $crate::$impl_type for PubMarker {}
-        impl $crate::$pub_impl_type for PubMarker {}
-        impl $crate::$marker_impl_type for PubMarker {}
-        impl $crate::$marker_pub_type for PubMarker {}
+        // SAFETY: Defers to the owner that it wraps.
+        unsafe impl< $( $impl_decl )* > $crate::GroupOwner for $owner_name< $( $args )* > {
+            type Cell<T> = <
+                $crate::qcell::$owner_type<$marker_name< $( $args )* >> as $crate::GroupOwner
+            >::Cell<T>;
 
-        impl<T> $crate::IsGTPubAccess for PubCell<T> {}
-        impl<T> $crate::$pub_impl_type for PubCell<T> {}
-        impl<T> $crate::$cell_pub_type for PubCell<T> {}
+            #[inline]
+            fn new_cell<T>(item : T) -> Self::Cell<T> {
+                <$crate::qcell::$owner_type<$marker_name< $( $args )* >> as $crate::GroupOwner>::new_cell(item)
+            }
 
-        impl $crate::IsGTPubAccess for PubOwner {}
-        impl $crate::$pub_impl_type for PubOwner {}
-        impl $crate::$owner_pub_type for PubOwner {}
+            #[inline]
+            fn ro<'a, T>(&'a self, cell : &'a Self::Cell<T>) -> &'a T {
+                $crate::GroupOwner::ro(&self.0, cell)
+            }
 
-        impl<T> $crate::GetEasyPubOwner for PubCell<T> {
-            type OwnerType = PubOwner;
+            #[inline]
+            fn rw<'a, T>(&'a mut self, cell : &'a Self::Cell<T>) -> &'a mut T {
+                $crate::GroupOwner::rw(&mut self.0, cell)
+            }
 
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
+            #[inline]
+            fn get_mut<T>(cell : &mut Self::Cell<T>) -> &mut T {
+                <$crate::qcell::$owner_type<$marker_name< $( $args )* >> as $crate::GroupOwner>::get_mut(cell)
             }
 
-            fn get_matching_owner_from(&self, src : & impl $crate::GetPubOwner<Self::OwnerType>) -> Self::OwnerType {
-                src.get_public_owner()
+            #[inline]
+            fn into_inner<T>(cell : Self::Cell<T>) -> T {
+                <$crate::qcell::$owner_type<$marker_name< $( $args )* >> as $crate::GroupOwner>::into_inner(cell)
             }
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn get_cell_impl() -> $crate::CellImpl {
-            PubMarker::get_cell_impl()
-        }
+        impl< $( $impl_decl )* > $crate::IsGTOwner for $owner_name< $( $args )* > {}
+        impl< $( $impl_decl )* > $crate::$impl_type for $owner_name< $( $args )* > {}
+        impl< $( $impl_decl )* > $crate::$access_type for $owner_name< $( $args )* > {}
+    };
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn has_private_access() -> bool {
-            false
+    // Owner lookup for cells that are not `qcell` types
+    {
+        @for_get_easy IsGTPvtAccess => $names:tt , $impl_decl:tt $args:tt
+    } => {
+        $crate::def_cells! {
+            @for_get_easy ( GetEasyPvtOwner , GetPvtOwner , get_private_owner ) =>
+            $names , $impl_decl $args
+        }
+    };
+    {
+        @for_get_easy IsGTUniAccess => $names:tt , $impl_decl:tt $args:tt
+    } => {
+        $crate::def_cells! {
+            @for_get_easy ( GetEasyUniOwner , GetUniOwner , get_uniform_owner ) =>
+            $names , $impl_decl $args
+        }
+    };
+    {
+        @for_get_easy IsGTPubAccess => $names:tt , $impl_decl:tt $args:tt
+    } => {
+        $crate::def_cells! {
+            @for_get_easy ( GetEasyPubOwner , GetPubOwner , get_public_owner ) =>
+            $names , $impl_decl $args
         }
+    };
+    {
+        @for_get_easy ( $get_easy:ident , $get_owner:ident , $get_method:ident ) =>
+        ( $cell_name:ident , $marker_name:ident ) ,
+        [ $( $impl_decl:tt )* ] [ $( $args:tt )* ]
+    } => {
+        impl< $( $impl_decl )* T : ?Sized > $crate::$get_easy for $cell_name< $( $args )* T > {
+            type OwnerType = <$marker_name< $( $args )* > as $crate::CellGroup>::Owner;
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn has_uniform_access() -> bool {
-            false
+            #[inline]
+            fn get_new_matching_owner(&self) -> Self::OwnerType {
+                <$marker_name< $( $args )* > as $crate::CellGroup>::new_owner()
+            }
+
+            #[inline]
+            fn get_matching_owner_from(
+                &self,
+                src : & impl $crate::$get_owner<Self::OwnerType>
+            ) -> Self::OwnerType {
+                src.$get_method()
+            }
         }
+    };
+
+    // Subcategories -> QCell
+    {
+        @for_slot q => &:($( #[$attr:meta] )*):& ->
+        (
+            $cell_name:ident , $owner_name:ident ,
+            ( $rc_name:ident , $arc_name:ident , $weak_name:ident )
+        ) ,
+        (
+            $has_access:ident , $new_owner:ident , $new_cell:ident ,
+            $new_rc_cell:ident , $new_arc_cell:ident ,
+            $_owner_msg:ident , $_cell_msg:ident
+        )
+    } => {
+        $( #[$attr] )*
+        pub type $cell_name<T> = $crate::qcell::QCell<T>;
+        $( #[$attr] )*
+        pub type $owner_name = $crate::qcell::QCellOwner;
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_public_access() -> bool {
+        pub fn $has_access() -> bool {
             true
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_private_owner() -> ! {
-            panic!("{}", $crate::pvt_owner_unavailable_msg())
+        pub fn $new_owner() -> $owner_name {
+            $owner_name::new()
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_uniform_owner() -> ! {
-            panic!("{}", $crate::uni_owner_unavailable_msg())
+        pub fn $new_cell<T>(owner: &$owner_name, item: T) -> $cell_name<T> {
+            owner.cell(item)
         }
 
-        #[inline]
+        $( #[$attr] )*
         #[allow(dead_code)]
-        pub fn new_public_owner() -> PubOwner {
-            PubOwner::new()
-        }
-
-        #[inline]
+        pub type $rc_name<T> = $crate::__shared::Rc<$cell_name<T>>;
+        $( #[$attr] )*
         #[allow(dead_code)]
-        pub fn new_private_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::pvt_cell_unavailable_msg())
-        }
+        pub type $arc_name<T> = $crate::__shared::Arc<$cell_name<T>>;
+        $( #[$attr] )*
+        #[allow(dead_code)]
+        pub type $weak_name<T> = $crate::__shared::Weak<$cell_name<T>>;
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_uniform_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::uni_cell_unavailable_msg())
+        pub fn $new_rc_cell<T>(owner: &$owner_name, item: T) -> $rc_name<T> {
+            $crate::__shared::Rc::new(owner.cell(item))
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_public_cell<T>(item: T) -> PubCell<T> {
-            PubCell::new(item)
+        pub fn $new_arc_cell<T>(owner: &$owner_name, item: T) -> $arc_name<T> {
+            $crate::__shared::Arc::new(owner.cell(item))
         }
     };
 
-    // Private group
+    // Subcategories -> LCell
     {
-        @for_private_group => &:($( #[$attr:meta] )*):& ->
+        @for_slot l => &:($( #[$attr:meta] )*):& ->
         (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $_owner_pub_type:ident ,
-            $cell_pvt_type:ident , $_cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $_pub_impl_type:ident
+            $cell_name:ident , $owner_name:ident ,
+            ( $rc_name:ident , $arc_name:ident , $weak_name:ident )
+        ) ,
+        (
+            $has_access:ident , $new_owner:ident , $new_cell:ident ,
+            $new_rc_cell:ident , $new_arc_cell:ident ,
+            $_owner_msg:ident , $_cell_msg:ident
         )
     } => {
-        use $crate::*;
-        $( #[$attr] )*
-        pub struct PvtMarker;
         $( #[$attr] )*
-        pub type PvtCell<T> = qcell::$cell_type<self::PvtMarker, T>;
+        pub type $cell_name<'id, T> = $crate::qcell::LCell<'id, T>;
         $( #[$attr] )*
-        pub type PvtOwner = qcell::$owner_type<self::PvtMarker>;
-
-        impl $crate::IsGTPvtAccess for PvtMarker {}
-        impl $crate::IsGTMarker for PvtMarker {}
-        impl $crate::$impl_type for PvtMarker {}
-        impl $crate::$pvt_impl_type for PvtMarker {}
-        impl $crate::$marker_impl_type for PvtMarker {}
-        impl $crate::$marker_pvt_type for PvtMarker {}
-
-        impl<T> $crate::IsGTPvtAccess for PvtCell<T> {}
-        impl<T> $crate::$pvt_impl_type for PvtCell<T> {}
-        impl<T> $crate::$cell_pvt_type for PvtCell<T> {}
-
-        impl $crate::IsGTPvtAccess for PvtOwner {}
-        impl $crate::$pvt_impl_type for PvtOwner {}
-        impl $crate::$owner_pvt_type for PvtOwner {}
-
-        impl<T> $crate::GetEasyPvtOwner for PvtCell<T> {
-            type OwnerType = PvtOwner;
-
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-
-            fn get_matching_owner_from(&self, src : & impl $crate::GetPvtOwner<Self::OwnerType>) -> Self::OwnerType {
-                src.get_private_owner()
-            }
-        }
-
-        #[inline]
-        #[allow(dead_code)]
-        pub fn get_cell_impl() -> $crate::CellImpl {
-            PvtMarker::get_cell_impl()
-        }
+        pub type $owner_name<'id> = $crate::qcell::LCellOwner<'id>;
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_private_access() -> bool {
+        pub fn $has_access() -> bool {
             true
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_uniform_access() -> bool {
-            false
+        pub fn $new_owner<R, F>(scope: F) -> R
+        where
+            F: for<'id> FnOnce($owner_name<'id>) -> R
+        {
+            let mut result = None;
+            $owner_name::scope(|owner| result = Some(scope(owner)));
+            result.expect("the scope closure was called")
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn has_public_access() -> bool {
-            false
+        pub fn $new_cell<'id, T>(item: T) -> $cell_name<'id, T> {
+            $cell_name::new(item)
         }
 
-        #[inline]
-        #[allow(dead_code)]
-        pub fn new_private_owner() -> PvtOwner {
-            PvtOwner::new()
+        $crate::__with_alloc! {
+            $( #[$attr] )*
+            #[allow(dead_code)]
+            pub type $rc_name<'id, T> = $crate::__shared::Rc<$cell_name<'id, T>>;
+            $( #[$attr] )*
+            #[allow(dead_code)]
+            pub type $arc_name<'id, T> = $crate::__shared::Arc<$cell_name<'id, T>>;
+            $( #[$attr] )*
+            #[allow(dead_code)]
+            pub type $weak_name<'id, T> = $crate::__shared::Weak<$cell_name<'id, T>>;
+
+            #[inline]
+            #[allow(dead_code)]
+            pub fn $new_rc_cell<'id, T>(item: T) -> $rc_name<'id, T> {
+                $crate::__shared::Rc::new($cell_name::new(item))
+            }
+
+            #[inline]
+            #[allow(dead_code)]
+            pub fn $new_arc_cell<'id, T>(item: T) -> $arc_name<'id, T> {
+                $crate::__shared::Arc::new($cell_name::new(item))
+            }
         }
+    };
 
+    // Subcategories -> unavailable
+    {
+        @for_slot absent => &:$attrs:tt:& -> ( ) ,
+        (
+            $has_access:ident , $new_owner:ident , $new_cell:ident ,
+            $new_rc_cell:ident , $new_arc_cell:ident ,
+            $owner_msg:ident , $cell_msg:ident
+        )
+    } => {
         #[inline]
         #[allow(dead_code)]
-        pub fn new_uniform_owner() -> ! {
-            panic!("{}", $crate::uni_owner_unavailable_msg())
+        pub fn $has_access() -> bool {
+            false
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_public_owner() -> ! {
-            panic!("{}", $crate::pub_owner_unavailable_msg())
+        pub fn $new_owner() -> ! {
+            panic!("{}", $crate::$owner_msg())
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_private_cell<T>(item: T) -> PvtCell<T> {
-            PvtCell::new(item)
+        pub fn $new_cell<T>(_item: T) -> ! {
+            panic!("{}", $crate::$cell_msg())
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_uniform_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::uni_cell_unavailable_msg())
+        pub fn $new_rc_cell<T>(_item: T) -> ! {
+            panic!("{}", $crate::$cell_msg())
         }
 
         #[inline]
         #[allow(dead_code)]
-        pub fn new_public_cell<T>(_item: T) -> ! {
-            panic!("{}", $crate::pub_cell_unavailable_msg())
+        pub fn $new_arc_cell<T>(_item: T) -> ! {
+            panic!("{}", $crate::$cell_msg())
         }
     };
 
-    // Individual evaluations -> uniform
+    // Individual evaluations
     {
-        @for_individual => &:$attrs:tt:& ( $group_visibility:vis mod ) ->
-        $cell_mod_name:ident ,
-        (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $owner_pub_type:ident ,
-            $cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $pub_impl_type:ident
-        ) , @for_uniform
+        @for_individual => &:$attrs:tt:& ( $( #[$mod_attr:meta] )* $group_visibility:vis mod ) ->
+        $cell_mod_name:ident , $cell_impl:ident , $renames:tt ,
+        $pvt_slot:tt , $uni_slot:tt , $pub_slot:tt
     } => {
+        $( #[$mod_attr] )*
         $group_visibility mod $cell_mod_name {
             $crate::def_cells! {
-                @for_uniform_group => &:$attrs:& ->
-                (
-                    $cell_type , $owner_type ,
-                    $marker_pvt_type , $marker_pub_type ,
-                    $owner_pvt_type , $owner_pub_type ,
-                    $cell_pvt_type , $cell_pub_type ,
-                    $impl_type , $marker_impl_type ,
-                    $pvt_impl_type , $pub_impl_type
-                )
+                @for_group => &:$attrs:& -> $cell_impl , $renames ,
+                $pvt_slot , $uni_slot , $pub_slot
             }
         }
     };
 
-    // Individual evaluations -> access
+    // Interpret block of definitions
     {
-        @for_individual => &:$attrs:tt:& ( $group_visibility:vis mod ) ->
-        $cell_mod_name:ident ,
-        (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $owner_pub_type:ident ,
-            $cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $pub_impl_type:ident
-        ) , @for_access
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TCellUniGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
     } => {
-        $group_visibility mod $cell_mod_name {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
             $crate::def_cells! {
-                @for_access_group => &:$attrs:& ->
-                (
-                    $cell_type , $owner_type ,
-                    $marker_pvt_type , $marker_pub_type ,
-                    $owner_pvt_type , $owner_pub_type ,
-                    $cell_pvt_type , $cell_pub_type ,
-                    $impl_type , $marker_impl_type ,
-                    $pvt_impl_type , $pub_impl_type
-                )
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , T , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( marked ( UniMarker , UniCell , UniOwner , ( RcUniCell , ArcUniCell , WeakUniCell ) , TCell , TCellOwner , IsTImpl , IsGTUniAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) )
             }
         }
     };
-
-    // Individual evaluations -> public
     {
-        @for_individual => &:$attrs:tt:& ( $group_visibility:vis mod ) ->
-        $cell_mod_name:ident ,
-        (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $owner_pub_type:ident ,
-            $cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $pub_impl_type:ident
-        ) , @for_public
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TCellAccGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , T , { $( $renames )* } ,
+                ( marked ( PvtMarker , PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) , TCell , TCellOwner , IsTImpl , IsGTPvtAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) ) ,
+                ( marked ( PubMarker , PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) , TCell , TCellOwner , IsTImpl , IsGTPubAccess , [ $( $params )? ] , $options ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TCellPubGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , T , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( absent ( ) ) ,
+                ( marked ( PubMarker , PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) , TCell , TCellOwner , IsTImpl , IsGTPubAccess , [ $( $params )? ] , $options ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TCellPvtGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , T , { $( $renames )* } ,
+                ( marked ( PvtMarker , PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) , TCell , TCellOwner , IsTImpl , IsGTPvtAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) ) ,
+                ( absent ( ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TLCellUniGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , TL , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( marked ( UniMarker , UniCell , UniOwner , ( RcUniCell , ArcUniCell , WeakUniCell ) , TLCell , TLCellOwner , IsTLImpl , IsGTUniAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TLCellAccGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , TL , { $( $renames )* } ,
+                ( marked ( PvtMarker , PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) , TLCell , TLCellOwner , IsTLImpl , IsGTPvtAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) ) ,
+                ( marked ( PubMarker , PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) , TLCell , TLCellOwner , IsTLImpl , IsGTPubAccess , [ $( $params )? ] , $options ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TLCellPubGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , TL , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( absent ( ) ) ,
+                ( marked ( PubMarker , PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) , TLCell , TLCellOwner , IsTLImpl , IsGTPubAccess , [ $( $params )? ] , $options ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : TLCellPvtGrp [ $( $params:tt )? ] { $( $renames:tt )* } $options:tt
+    } => {
+        $crate::__require_std! {
+            "`TLCell` groups require the `std` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , TL , { $( $renames )* } ,
+                ( marked ( PvtMarker , PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) , TLCell , TLCellOwner , IsTLImpl , IsGTPvtAccess , [ $( $params )? ] , $options ) ) ,
+                ( absent ( ) ) ,
+                ( absent ( ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : QCellUniGrp [ ] { $( $renames:tt )* } { }
+    } => {
+        $crate::__require_alloc! {
+            "`QCell` groups require the `alloc` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , Q , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( q ( UniCell , UniOwner , ( RcUniCell , ArcUniCell , WeakUniCell ) ) ) ,
+                ( absent ( ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : QCellAccGrp [ ] { $( $renames:tt )* } { }
+    } => {
+        $crate::__require_alloc! {
+            "`QCell` groups require the `alloc` feature of `cell_wrappers`." =>
+            $crate::def_cells! {
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , Q , { $( $renames )* } ,
+                ( q ( PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) ) ) ,
+                ( absent ( ) ) ,
+                ( q ( PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) ) )
+            }
+        }
+    };
+    {
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : QCellPubGrp [ ] { $( $renames:tt )* } { }
     } => {
-        $group_visibility mod $cell_mod_name {
+        $crate::__require_alloc! {
+            "`QCell` groups require the `alloc` feature of `cell_wrappers`." =>
             $crate::def_cells! {
-                @for_public_group => &:$attrs:& ->
-                (
-                    $cell_type , $owner_type ,
-                    $marker_pvt_type , $marker_pub_type ,
-                    $owner_pvt_type , $owner_pub_type ,
-                    $cell_pvt_type , $cell_pub_type ,
-                    $impl_type , $marker_impl_type ,
-                    $pvt_impl_type , $pub_impl_type
-                )
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , Q , { $( $renames )* } ,
+                ( absent ( ) ) ,
+                ( absent ( ) ) ,
+                ( q ( PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) ) )
             }
         }
     };
-
-    // Individual evaluations -> private
     {
-        @for_individual => &:$attrs:tt:& ( $group_visibility:vis mod ) ->
-        $cell_mod_name:ident ,
-        (
-            $cell_type:ident , $owner_type:ident ,
-            $marker_pvt_type:ident , $marker_pub_type:ident ,
-            $owner_pvt_type:ident , $owner_pub_type:ident ,
-            $cell_pvt_type:ident , $cell_pub_type:ident ,
-            $impl_type:ident , $marker_impl_type:ident ,
-            $pvt_impl_type:ident , $pub_impl_type:ident
-        ) , @for_private
+        @line =>
+        &:$attrs:tt:&
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : QCellPvtGrp [ ] { $( $renames:tt )* } { }
     } => {
-        $group_visibility mod $cell_mod_name {
+        $crate::__require_alloc! {
+            "`QCell` groups require the `alloc` feature of `cell_wrappers`." =>
             $crate::def_cells! {
-                @for_private_group => &:$attrs:& ->
-                (
-                    $cell_type , $owner_type ,
-                    $marker_pvt_type , $marker_pub_type ,
-                    $owner_pvt_type , $owner_pub_type ,
-                    $cell_pvt_type , $cell_pub_type ,
-                    $impl_type , $marker_impl_type ,
-                    $pvt_impl_type , $pub_impl_type
-                )
+                @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+                $cell_mod_name , Q , { $( $renames )* } ,
+                ( q ( PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) ) ) ,
+                ( absent ( ) ) ,
+                ( absent ( ) )
             }
         }
     };
-
-    // Interpret block of definitions
     {
         @line =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TCellUniGrp
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : LCellUniGrp [ ] { $( $renames:tt )* } { }
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TCell , TCellOwner ,
-                IsTUniMarker , IsTUniMarker ,
-                IsTUniOwner , IsTUniOwner ,
-                IsTUniCell , IsTUniCell ,
-                IsTImpl , IsTMarker ,
-                IsTUniAccess , IsTUniAccess
-            ), @for_uniform
+            @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+            $cell_mod_name , L , { $( $renames )* } ,
+            ( absent ( ) ) ,
+            ( l ( UniCell , UniOwner , ( RcUniCell , ArcUniCell , WeakUniCell ) ) ) ,
+            ( absent ( ) )
         }
     };
     {
         @line =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TCellAccGrp
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : LCellAccGrp [ ] { $( $renames:tt )* } { }
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TCell , TCellOwner ,
-                IsTPvtMarker , IsTPubMarker ,
-                IsTPvtOwner , IsTPubOwner ,
-                IsTPvtCell , IsTPubCell ,
-                IsTImpl , IsTMarker ,
-                IsTPvtAccess , IsTPubAccess
-            ), @for_access
+            @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+            $cell_mod_name , L , { $( $renames )* } ,
+            ( l ( PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) ) ) ,
+            ( absent ( ) ) ,
+            ( l ( PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) ) )
         }
     };
     {
         @line =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TLCellUniGrp
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : LCellPubGrp [ ] { $( $renames:tt )* } { }
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TLCell , TLCellOwner ,
-                IsTLUniMarker , IsTLUniMarker ,
-                IsTLUniOwner , IsTLUniOwner ,
-                IsTLUniCell , IsTLUniCell ,
-                IsTLImpl , IsTLMarker ,
-                IsTLUniAccess , IsTLUniAccess
-            ), @for_uniform
+            @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+            $cell_mod_name , L , { $( $renames )* } ,
+            ( absent ( ) ) ,
+            ( absent ( ) ) ,
+            ( l ( PubCell , PubOwner , ( RcPubCell , ArcPubCell , WeakPubCell ) ) )
         }
     };
     {
         @line =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TLCellAccGrp
+        [ $( #[$mod_attr:meta] )* $group_visibility:vis mod ] $cell_mod_name:ident : LCellPvtGrp [ ] { $( $renames:tt )* } { }
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TLCell , TLCellOwner ,
-                IsTLPvtMarker , IsTLPubMarker ,
-                IsTLPvtOwner , IsTLPubOwner ,
-                IsTLPvtCell , IsTLPubCell ,
-                IsTLImpl , IsTLMarker ,
-                IsTLPvtAccess , IsTLPubAccess
-            ), @for_access
+            @for_individual => &:$attrs:& ( $( #[$mod_attr] )* $group_visibility mod ) ->
+            $cell_mod_name , L , { $( $renames )* } ,
+            ( l ( PvtCell , PvtOwner , ( RcPvtCell , ArcPvtCell , WeakPvtCell ) ) ) ,
+            ( absent ( ) ) ,
+            ( absent ( ) )
         }
     };
+
     {
         @line =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TCellPubGrp
+        [ $( $mod_header:tt )* ] $cell_mod_name:ident : $category:ident
+        [ $( $params:tt )* ] { $( $renames:tt )* } { $( $options:tt )+ }
     } => {
-        $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TCell , TCellOwner ,
-                IsTPubMarker , IsTPubMarker ,
-                IsTPubOwner , IsTPubOwner ,
-                IsTPubCell , IsTPubCell ,
-                IsTImpl , IsTMarker ,
-                IsTPubAccess , IsTPubAccess
-            ), @for_public
-        }
+        compile_error!(concat!(
+            "`", stringify!($category), "` groups do not accept `with` options."
+        ));
     };
+
+    // Cluster definitions - Check for array
     {
-        @line =>
+        @check_cluster =>
         &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TCellPvtGrp
+        { $( $entries:tt )* }
+    } => {
+        $crate::def_cells! { @cluster_entry => &:$attrs:& $( $entries )* }
+    };
+    {
+        @cluster_entry => &:$attrs:tt:&
+    } => {};
+    {
+        @cluster_entry => &:$attrs:tt:&
+        , $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @cluster_entry => &:$attrs:& $( $rest )* }
+    };
+    {
+        @cluster_entry => &:$attrs:tt:&
+        $( $rest:tt )+
+    } => {
+        $crate::def_cells! { @cluster_attrs => &:$attrs:& ( ) $( $rest )+ }
+    };
+    // Cluster definitions - Entry attributes and visibility
+    {
+        @cluster_attrs => &:$attrs:tt:& ( $( #[$entry_attr:meta] )* )
+        # [ $attr:meta ] $( $rest:tt )*
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TCell , TCellOwner ,
-                IsTPvtMarker , IsTPvtMarker ,
-                IsTPvtOwner , IsTPvtOwner ,
-                IsTPvtCell , IsTPvtCell ,
-                IsTImpl , IsTMarker ,
-                IsTPvtAccess , IsTPvtAccess
-            ), @for_private
+            @cluster_attrs => &:$attrs:& ( $( #[$entry_attr] )* #[$attr] ) $( $rest )*
         }
     };
     {
-        @line =>
-        &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TLCellPubGrp
+        @cluster_attrs => &:$attrs:tt:& $entry_attrs:tt
+        [ $group_visibility:vis mod ] $( $rest:tt )*
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TLCell , TLCellOwner ,
-                IsTLPubMarker , IsTLPubMarker ,
-                IsTLPubOwner , IsTLPubOwner ,
-                IsTLPubCell , IsTLPubCell ,
-                IsTLImpl , IsTLMarker ,
-                IsTLPubAccess , IsTLPubAccess
-            ), @for_public
+            @cluster_item => &:$attrs:& $entry_attrs [ $group_visibility mod ] $( $rest )*
         }
     };
     {
-        @line =>
-        &:$attrs:tt:&
-        [ $group_visibility:vis mod ] $cell_mod_name:ident : TLCellPvtGrp
+        @cluster_attrs => &:$attrs:tt:& $entry_attrs:tt
+        $( $rest:tt )*
     } => {
         $crate::def_cells! {
-            @for_individual => &:$attrs:& ( $group_visibility mod ) ->
-            $cell_mod_name , (
-                TLCell , TLCellOwner ,
-                IsTLPvtMarker , IsTLPvtMarker ,
-                IsTLPvtOwner , IsTLPvtOwner ,
-                IsTLPvtCell , IsTLPvtCell ,
-                IsTLImpl , IsTLMarker ,
-                IsTLPvtAccess , IsTLPvtAccess
-            ), @for_private
+            @cluster_item => &:$attrs:& $entry_attrs [ pub mod ] $( $rest )*
         }
     };
-
-    // Cluster definitions - Check for array
+    // Cluster definitions - Entries
     {
-        @check_cluster =>
-        &:$attrs:tt:&
-        { $(
-            $short_straw:ident
-            $( : $short_category:ident )?
-            $( :: $extension:tt )?
-        ),+ }
-    } => {
-        $(
-            $( $crate::def_cells! {
-                @line =>
+        @cluster_item => &:$attrs:tt:& ( $( #[$entry_attr:meta] )* )
+        [ $group_visibility:vis mod ] $short_straw:ident :: $extension:tt
+        $( $rest:tt )*
+    } => {
+        $( #[$entry_attr] )*
+        $group_visibility mod $short_straw {
+            $crate::def_cells! {
+                @check_cluster => &:$attrs:& $extension
+            }
+        }
+        $crate::def_cells! { @cluster_entry => &:$attrs:& $( $rest )* }
+    };
+    {
+        @cluster_item => &:$attrs:tt:& ( $( #[$entry_attr:meta] )* )
+        [ $group_visibility:vis mod ] $short_straw:ident : $short_category:ident
+        $( $rest:tt )*
+    } => {
+        $crate::def_cells! {
+            @for_category => (
                 &:$attrs:&
-                [ pub mod ] $short_straw : $short_category
-            } )?
-            $( pub mod $short_straw {
-                $crate::def_cells! {
-                    @check_cluster => &:$attrs:& $extension
-                }
-            } )?
-        )+
+                [ $( #[$entry_attr] )* $group_visibility mod ] $short_straw : $short_category
+            )
+            ( @cluster_entry => &:$attrs:& ) $( $rest )*
+        }
     };
     // Cluster definitions - Stage 0
     {
@@ -2768,24 +5158,81 @@ macro_rules! def_cells {
 
     // Line splitter
     {
-        $(
-            $( #[$attr:meta] )*
-            [ $group_visibility:vis mod ]
-            $header:ident
-            $( :: $cluster:tt ; )?
-            $( : $category:ident ; )? $(;)*
-        )+
-    } => {
-        $(
-            $crate::def_cells! { @line =>
-                &:( $( #[$attr])* ):&
-                [ $group_visibility mod ]
-                $header
-                $( :: $cluster )?
-                $( : $category )?
-            }
-        )+
+        $( #[$attr:meta] )*
+        [ $group_visibility:vis mod ]
+        $header:ident :: $cluster:tt ;
+        $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @line =>
+            &:( $( #[$attr])* ):&
+            [ $group_visibility mod ]
+            $header :: $cluster
+        }
+        $crate::def_cells! { $( $rest )* }
+    };
+    {
+        $( #[$attr:meta] )*
+        [ $group_visibility:vis mod ]
+        $header:ident : $category:ident
+        $( $rest:tt )*
+    } => {
+        $crate::def_cells! {
+            @for_category => ( &:( $( #[$attr] )* ):& [ $group_visibility mod ] $header : $category )
+            ( ) $( $rest )*
+        }
+    };
+    {
+        @for_category => $line:tt $next_line:tt < $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @split_params => $line $next_line [ ] $( $rest )* }
+    };
+    {
+        @for_category => $line:tt $next_line:tt $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @for_renames => $line [ ] $next_line $( $rest )* }
+    };
+    {
+        @split_params => $line:tt $next_line:tt [ $( $params:tt )* ] > $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @for_renames => $line [ ( $( $params )* ) ] $next_line $( $rest )* }
+    };
+    {
+        @split_params => $line:tt $next_line:tt [ $( $params:tt )* ] $next:tt $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @split_params => $line $next_line [ $( $params )* $next ] $( $rest )* }
+    };
+    {
+        @for_renames => $line:tt $params:tt $next_line:tt
+        as { $( $renames:tt )* } $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @for_options => $line $params { $( $renames )* } $next_line $( $rest )* }
+    };
+    {
+        @for_renames => $line:tt $params:tt $next_line:tt
+        $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @for_options => $line $params { } $next_line $( $rest )* }
+    };
+    {
+        @for_options => ( $( $line:tt )* ) $params:tt $renames:tt ( $( $next_line:tt )* )
+        with { $( $options:tt )* } $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @line => $( $line )* $params $renames { $( $options )* } }
+        $crate::def_cells! { $( $next_line )* $( $rest )* }
+    };
+    {
+        @for_options => ( $( $line:tt )* ) $params:tt $renames:tt ( $( $next_line:tt )* )
+        $( $rest:tt )*
+    } => {
+        $crate::def_cells! { @line => $( $line )* $params $renames { } }
+        $crate::def_cells! { $( $next_line )* $( $rest )* }
+    };
+    {
+        ; $( $rest:tt )*
+    } => {
+        $crate::def_cells! { $( $rest )* }
     };
+    { } => {};
 }
 
 /// This module is not to be used. It's simply included to provide
@@ -2807,10 +5254,9 @@ macro_rules! def_cells {
 /// [`impl_get_pvt`]: ./macro.impl_get_pvt.html
 /// [`impl_get_pub`]: ./macro.impl_get_pub.html
 /// [`impl_get_uni`]: ./macro.impl_get_uni.html
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 mod example_macro_results {
-    use super::*;
-
     /// This module is the result of the following source code:
     /// 
     /// ```rust
@@ -2823,7 +5269,6 @@ mod example_macro_results {
     /// 
     /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
     pub mod example_uni_grp {
-        use crate::*;
         /// A uniform marker struct for a uniform [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
@@ -2831,34 +5276,19 @@ mod example_macro_results {
         /// A uniform [`TLCell`] `type` for a uniform [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-        pub type UniCell<T> = qcell::TLCell<UniMarker, T>;
+        pub type UniCell<T> = crate::qcell::TLCell<UniMarker, T>;
         /// A uniform [`TLCellOwner`] `type` for a uniform [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
         /// [`TLCellOwner`]: https://docs.rs/qcell/latest/qcell/struct.TLCellOwner.html
-        pub type UniOwner = qcell::TLCellOwner<UniMarker>;
+        pub type UniOwner = crate::qcell::TLCellOwner<UniMarker>;
         impl crate::IsGTUniAccess for UniMarker {}
         impl crate::IsGTMarker for UniMarker {}
         impl crate::IsTLImpl for UniMarker {}
-        impl crate::IsTLUniAccess for UniMarker {}
-        impl crate::IsTLMarker for UniMarker {}
-        impl crate::IsTLUniMarker for UniMarker {}
-        impl<T> crate::IsGTUniAccess for UniCell<T> {}
-        impl<T> crate::IsTLUniAccess for UniCell<T> {}
-        impl<T> crate::IsTLUniCell for UniCell<T> {}
-        impl crate::IsGTUniAccess for UniOwner {}
-        impl crate::IsTLUniAccess for UniOwner {}
-        impl crate::IsTLUniOwner for UniOwner {}
-        impl<T> crate::GetEasyUniOwner for UniCell<T> {
-            type OwnerType = UniOwner;
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-            fn get_matching_owner_from(
-                &self,
-                src: &impl crate::GetUniOwner<Self::OwnerType>,
-            ) -> Self::OwnerType {
-                src.get_uniform_owner()
+        impl crate::CellGroup for UniMarker {
+            type Owner = UniOwner;
+            fn new_owner() -> Self::Owner {
+                Self::Owner::new()
             }
         }
 
@@ -2866,7 +5296,7 @@ mod example_macro_results {
         #[inline]
         #[allow(dead_code)]
         pub fn get_cell_impl() -> crate::CellImpl {
-            UniMarker::get_cell_impl()
+            crate::CellImpl::TL
         }
 
         /// Shows whether or not this group can provide
@@ -2961,7 +5391,6 @@ mod example_macro_results {
     /// 
     /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
     pub mod example_acc_grp {
-        use crate::*;
         /// A public marker struct for an access [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
@@ -2969,34 +5398,19 @@ mod example_macro_results {
         /// A public [`TLCell`] `type` for an access [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-        pub type PubCell<T> = qcell::TLCell<self::PubMarker, T>;
+        pub type PubCell<T> = crate::qcell::TLCell<self::PubMarker, T>;
         /// A public [`TLCellOwner`] `type` for an access [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
         /// [`TLCellOwner`]: https://docs.rs/qcell/latest/qcell/struct.TLCellOwner.html
-        pub type PubOwner = qcell::TLCellOwner<self::PubMarker>;
+        pub type PubOwner = crate::qcell::TLCellOwner<self::PubMarker>;
         impl crate::IsGTPubAccess for PubMarker {}
         impl crate::IsGTMarker for PubMarker {}
         impl crate::IsTLImpl for PubMarker {}
-        impl crate::IsTLPubAccess for PubMarker {}
-        impl crate::IsTLMarker for PubMarker {}
-        impl crate::IsTLPubMarker for PubMarker {}
-        impl<T> crate::IsGTPubAccess for PubCell<T> {}
-        impl<T> crate::IsTLPubAccess for PubCell<T> {}
-        impl<T> crate::IsTLPubCell for PubCell<T> {}
-        impl crate::IsGTPubAccess for PubOwner {}
-        impl crate::IsTLPubAccess for PubOwner {}
-        impl crate::IsTLPubOwner for PubOwner {}
-        impl<T> crate::GetEasyPubOwner for PubCell<T> {
-            type OwnerType = PubOwner;
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-            fn get_matching_owner_from(
-                &self,
-                src: &impl crate::GetPubOwner<Self::OwnerType>,
-            ) -> Self::OwnerType {
-                src.get_public_owner()
+        impl crate::CellGroup for PubMarker {
+            type Owner = PubOwner;
+            fn new_owner() -> Self::Owner {
+                Self::Owner::new()
             }
         }
         /// A private marker struct for an access [`TLCell`] group.
@@ -3006,34 +5420,19 @@ mod example_macro_results {
         /// A private [`TLCell`] `type` for an access [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-        pub type PvtCell<T> = qcell::TLCell<self::PvtMarker, T>;
+        pub type PvtCell<T> = crate::qcell::TLCell<self::PvtMarker, T>;
         /// A private [`TLCellOwner`] `type` for an access [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
         /// [`TLCellOwner`]: https://docs.rs/qcell/latest/qcell/struct.TLCellOwner.html
-        pub type PvtOwner = qcell::TLCellOwner<self::PvtMarker>;
+        pub type PvtOwner = crate::qcell::TLCellOwner<self::PvtMarker>;
         impl crate::IsGTPvtAccess for PvtMarker {}
         impl crate::IsGTMarker for PvtMarker {}
         impl crate::IsTLImpl for PvtMarker {}
-        impl crate::IsTLPvtAccess for PvtMarker {}
-        impl crate::IsTLMarker for PvtMarker {}
-        impl crate::IsTLPvtMarker for PvtMarker {}
-        impl<T> crate::IsGTPvtAccess for PvtCell<T> {}
-        impl<T> crate::IsTLPvtAccess for PvtCell<T> {}
-        impl<T> crate::IsTLPvtCell for PvtCell<T> {}
-        impl crate::IsGTPvtAccess for PvtOwner {}
-        impl crate::IsTLPvtAccess for PvtOwner {}
-        impl crate::IsTLPvtOwner for PvtOwner {}
-        impl<T> crate::GetEasyPvtOwner for PvtCell<T> {
-            type OwnerType = PvtOwner;
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-            fn get_matching_owner_from(
-                &self,
-                src: &impl crate::GetPvtOwner<Self::OwnerType>,
-            ) -> Self::OwnerType {
-                src.get_private_owner()
+        impl crate::CellGroup for PvtMarker {
+            type Owner = PvtOwner;
+            fn new_owner() -> Self::Owner {
+                Self::Owner::new()
             }
         }
 
@@ -3041,7 +5440,7 @@ mod example_macro_results {
         #[inline]
         #[allow(dead_code)]
         pub fn get_cell_impl() -> crate::CellImpl {
-            PvtMarker::get_cell_impl()
+            crate::CellImpl::TL
         }
 
         /// Shows whether or not this group can provide
@@ -3129,7 +5528,6 @@ mod example_macro_results {
     /// 
     /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
     pub mod example_pub_grp {
-        use crate::*;
         /// A public marker struct for a public [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
@@ -3137,34 +5535,19 @@ mod example_macro_results {
         /// A public [`TLCell`] `type` for a public [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-        pub type PubCell<T> = qcell::TLCell<self::PubMarker, T>;
+        pub type PubCell<T> = crate::qcell::TLCell<self::PubMarker, T>;
         /// A public [`TLCellOwner`] `type` for a public [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
         /// [`TLCellOwner`]: https://docs.rs/qcell/latest/qcell/struct.TLCellOwner.html
-        pub type PubOwner = qcell::TLCellOwner<self::PubMarker>;
+        pub type PubOwner = crate::qcell::TLCellOwner<self::PubMarker>;
         impl crate::IsGTPubAccess for PubMarker {}
         impl crate::IsGTMarker for PubMarker {}
         impl crate::IsTLImpl for PubMarker {}
-        impl crate::IsTLPubAccess for PubMarker {}
-        impl crate::IsTLMarker for PubMarker {}
-        impl crate::IsTLPubMarker for PubMarker {}
-        impl<T> crate::IsGTPubAccess for PubCell<T> {}
-        impl<T> crate::IsTLPubAccess for PubCell<T> {}
-        impl<T> crate::IsTLPubCell for PubCell<T> {}
-        impl crate::IsGTPubAccess for PubOwner {}
-        impl crate::IsTLPubAccess for PubOwner {}
-        impl crate::IsTLPubOwner for PubOwner {}
-        impl<T> crate::GetEasyPubOwner for PubCell<T> {
-            type OwnerType = PubOwner;
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-            fn get_matching_owner_from(
-                &self,
-                src: &impl crate::GetPubOwner<Self::OwnerType>,
-            ) -> Self::OwnerType {
-                src.get_public_owner()
+        impl crate::CellGroup for PubMarker {
+            type Owner = PubOwner;
+            fn new_owner() -> Self::Owner {
+                Self::Owner::new()
             }
         }
 
@@ -3172,7 +5555,7 @@ mod example_macro_results {
         #[inline]
         #[allow(dead_code)]
         pub fn get_cell_impl() -> crate::CellImpl {
-            PubMarker::get_cell_impl()
+            crate::CellImpl::TL
         }
 
         /// Shows whether or not this group can provide
@@ -3266,7 +5649,6 @@ mod example_macro_results {
     /// 
     /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
     pub mod example_pvt_grp {
-        use crate::*;
         /// A private marker struct for a private [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
@@ -3274,34 +5656,19 @@ mod example_macro_results {
         /// A private [`TLCell`] `type` for a private [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
-        pub type PvtCell<T> = qcell::TLCell<self::PvtMarker, T>;
+        pub type PvtCell<T> = crate::qcell::TLCell<self::PvtMarker, T>;
         /// A private [`TLCellOwner`] `type` for a private [`TLCell`] group.
         /// 
         /// [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
         /// [`TLCellOwner`]: https://docs.rs/qcell/latest/qcell/struct.TLCellOwner.html
-        pub type PvtOwner = qcell::TLCellOwner<self::PvtMarker>;
+        pub type PvtOwner = crate::qcell::TLCellOwner<self::PvtMarker>;
         impl crate::IsGTPvtAccess for PvtMarker {}
         impl crate::IsGTMarker for PvtMarker {}
         impl crate::IsTLImpl for PvtMarker {}
-        impl crate::IsTLPvtAccess for PvtMarker {}
-        impl crate::IsTLMarker for PvtMarker {}
-        impl crate::IsTLPvtMarker for PvtMarker {}
-        impl<T> crate::IsGTPvtAccess for PvtCell<T> {}
-        impl<T> crate::IsTLPvtAccess for PvtCell<T> {}
-        impl<T> crate::IsTLPvtCell for PvtCell<T> {}
-        impl crate::IsGTPvtAccess for PvtOwner {}
-        impl crate::IsTLPvtAccess for PvtOwner {}
-        impl crate::IsTLPvtOwner for PvtOwner {}
-        impl<T> crate::GetEasyPvtOwner for PvtCell<T> {
-            type OwnerType = PvtOwner;
-            fn get_new_matching_owner(&self) -> Self::OwnerType {
-                Self::OwnerType::new()
-            }
-            fn get_matching_owner_from(
-                &self,
-                src: &impl crate::GetPvtOwner<Self::OwnerType>,
-            ) -> Self::OwnerType {
-                src.get_private_owner()
+        impl crate::CellGroup for PvtMarker {
+            type Owner = PvtOwner;
+            fn new_owner() -> Self::Owner {
+                Self::Owner::new()
             }
         }
 
@@ -3309,7 +5676,7 @@ mod example_macro_results {
         #[inline]
         #[allow(dead_code)]
         pub fn get_cell_impl() -> crate::CellImpl {
-            PvtMarker::get_cell_impl()
+            crate::CellImpl::TL
         }
 
         /// Shows whether or not this group can provide
@@ -3394,19 +5761,16 @@ mod example_macro_results {
     // new_t_group!(OtherOwner[OtherMarker] => OtherCell<T>);
     struct OtherMarker;
     impl crate::IsGTMarker for OtherMarker {}
-    impl crate::IsTMarker for OtherMarker {}
     impl crate::IsTImpl for OtherMarker {}
     impl crate::IsGTUniAccess for OtherMarker {}
-    impl crate::IsTUniAccess for OtherMarker {}
-    impl crate::IsTUniMarker for OtherMarker {}
-    type OtherOwner = qcell::TCellOwner<OtherMarker>;
-    impl crate::IsGTUniAccess for OtherOwner {}
-    impl crate::IsTUniAccess for OtherOwner {}
-    impl crate::IsTUniOwner for OtherOwner {}
-    type OtherCell<T> = qcell::TCell<OtherMarker, T>;
-    impl<T> crate::IsGTUniAccess for OtherCell<T> {}
-    impl<T> crate::IsTUniAccess for OtherCell<T> {}
-    impl<T> crate::IsTUniCell for OtherCell<T> {}
+    type OtherOwner = crate::qcell::TCellOwner<OtherMarker>;
+    type OtherCell<T> = crate::qcell::TCell<OtherMarker, T>;
+    impl crate::CellGroup for OtherMarker {
+        type Owner = OtherOwner;
+        fn new_owner() -> Self::Owner {
+            OtherOwner::new()
+        }
+    }
 
     struct ExampleStruct {
         example_uni_cell: example_uni_grp::UniCell<i32>,
@@ -3468,9 +5832,11 @@ mod example_macro_results {
             assert_eq!(outer_container, 7);
 
             {
-                let __scope_owner = &mut (self
-                    .example_uni_cell
-                    .get_matching_owner_from(self));
+                let __scope_owner = &mut ({
+                    #[allow(unused_imports)]
+                    use crate::{GetEasyPvtOwner as _, GetEasyPubOwner as _, GetEasyUniOwner as _};
+                    self.example_uni_cell.get_matching_owner_from(self)
+                });
                 let example_container = self.example_uni_cell.rw(__scope_owner);
                 {
                     *example_container += 1;
@@ -3481,9 +5847,11 @@ mod example_macro_results {
                 }
             }
             {
-                let __scope_owner = &(self
-                    .example_pvt_cell
-                    .get_matching_owner_from(self));
+                let __scope_owner = &({
+                    #[allow(unused_imports)]
+                    use crate::{GetEasyPvtOwner as _, GetEasyPubOwner as _, GetEasyUniOwner as _};
+                    self.example_pvt_cell.get_matching_owner_from(self)
+                });
                 outer_container = (*self.example_pvt_cell.ro(__scope_owner)) as u8;
                 {
                     // The expansion of assert_eq!() was manually reverted
@@ -3588,9 +5956,9 @@ mod example_macro_results {
 
 /// This module is the source code that was used for
 /// building what is found in [`./example_macro_results/index.html`].
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 mod example_macro_results_source_code {
-    use super::*;
     def_cells! {
         [pub mod] example_uni_grp: TLCellUniGrp;
         [pub mod] example_acc_grp: TLCellAccGrp;
@@ -3658,7 +6026,91 @@ mod example_macro_results_source_code {
     }
 }
 
+// Tests of the groups which are available without the `std` feature.
 #[cfg(test)]
+mod no_std_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn q_acc_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: QCellAccGrp;
+        }
+        assert_eq!(test_grp::get_cell_impl(), CellImpl::Q);
+        assert!(test_grp::has_private_access());
+        assert!(!test_grp::has_uniform_access());
+        assert!(test_grp::has_public_access());
+
+        let mut test_owner = test_grp::new_public_owner();
+        let test_cell = test_grp::new_public_cell(&test_owner, 1);
+
+        assert_eq!(test_owner.get_self_cell_impl(), CellImpl::Q);
+        assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
+        assert_eq!(test_cell.get_self_cell_impl(), CellImpl::Q);
+        assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
+
+        *test_cell.rw(&mut test_owner) += 1;
+        assert_eq!(*test_cell.ro(&test_owner), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn q_acc_uni_owner_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: QCellAccGrp;
+        }
+        test_grp::new_uniform_owner();
+    }
+
+    #[test]
+    fn l_uni_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: LCellUniGrp;
+        }
+        assert_eq!(test_grp::get_cell_impl(), CellImpl::L);
+        assert!(!test_grp::has_private_access());
+        assert!(test_grp::has_uniform_access());
+        assert!(!test_grp::has_public_access());
+
+        test_grp::new_uniform_owner(|mut test_owner| {
+            let test_cell = test_grp::new_uniform_cell(1);
+
+            assert_eq!(test_owner.get_self_cell_impl(), CellImpl::L);
+            assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
+            assert_eq!(test_cell.get_self_cell_impl(), CellImpl::L);
+            assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
+
+            *test_cell.rw(&mut test_owner) += 1;
+            assert_eq!(*test_cell.ro(&test_owner), 2);
+        });
+    }
+
+    #[test]
+    fn l_owner_scope_result_test() {
+        def_cells! {
+            [pub mod] test_grp: LCellUniGrp;
+        }
+        let total = test_grp::new_uniform_owner(|mut test_owner| {
+            let test_cell = test_grp::new_uniform_cell(20);
+            *test_cell.rw(&mut test_owner) += 1;
+            *test_cell.ro(&test_owner) * 2
+        });
+        assert_eq!(total, 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn l_pvt_pub_cell_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: LCellPvtGrp;
+        }
+        test_grp::new_public_cell(1);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -4137,90 +6589,530 @@ mod tests {
         assert_eq!(test_grp::PubMarker::get_cell_role(), CellRoles::Marker);
         assert_eq!(test_grp::PubMarker::get_access_level(), CellAccessLevels::Public);
 
-        assert_eq!(test_grp::PubOwner::get_cell_impl(), CellImpl::TL);
-        assert_eq!(test_grp::PubOwner::get_cell_role(), CellRoles::Owner);
-        assert_eq!(test_grp::PubOwner::get_access_level(), CellAccessLevels::Public);
+        assert_eq!(test_grp::PubOwner::get_cell_impl(), CellImpl::TL);
+        assert_eq!(test_grp::PubOwner::get_cell_role(), CellRoles::Owner);
+        assert_eq!(test_grp::PubOwner::get_access_level(), CellAccessLevels::Public);
+
+        assert_eq!(test_grp::PubCell::<i32>::get_cell_impl(), CellImpl::TL);
+        assert_eq!(test_grp::PubCell::<i32>::get_cell_role(), CellRoles::Cell);
+        assert_eq!(test_grp::PubCell::<i32>::get_access_level(), CellAccessLevels::Public);
+
+        let test_owner = test_grp::PubOwner::new();
+
+        assert_eq!(test_owner.get_self_cell_impl(), CellImpl::TL);
+        assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
+        assert_eq!(test_owner.get_self_access_level(), CellAccessLevels::Public);
+
+        let test_cell = test_grp::PubCell::new(1);
+
+        assert_eq!(test_cell.get_self_cell_impl(), CellImpl::TL);
+        assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
+        assert_eq!(test_cell.get_self_access_level(), CellAccessLevels::Public);
+
+        assert_eq!(test_grp::PvtMarker::get_cell_impl(), CellImpl::TL);
+        assert_eq!(test_grp::PvtMarker::get_cell_role(), CellRoles::Marker);
+        assert_eq!(test_grp::PvtMarker::get_access_level(), CellAccessLevels::Private);
+
+        assert_eq!(test_grp::PvtOwner::get_cell_impl(), CellImpl::TL);
+        assert_eq!(test_grp::PvtOwner::get_cell_role(), CellRoles::Owner);
+        assert_eq!(test_grp::PvtOwner::get_access_level(), CellAccessLevels::Private);
+
+        assert_eq!(test_grp::PvtCell::<i32>::get_cell_impl(), CellImpl::TL);
+        assert_eq!(test_grp::PvtCell::<i32>::get_cell_role(), CellRoles::Cell);
+        assert_eq!(test_grp::PvtCell::<i32>::get_access_level(), CellAccessLevels::Private);
+
+        let test_owner = test_grp::PvtOwner::new();
+
+        assert_eq!(test_owner.get_self_cell_impl(), CellImpl::TL);
+        assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
+        assert_eq!(test_owner.get_self_access_level(), CellAccessLevels::Private);
+
+        let test_cell = test_grp::PvtCell::new(1);
+
+        assert_eq!(test_cell.get_self_cell_impl(), CellImpl::TL);
+        assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
+        assert_eq!(test_cell.get_self_access_level(), CellAccessLevels::Private);
+    }
+
+    #[test]
+    fn tl_acc_pvt_owner_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: TLCellAccGrp;
+        }
+        got_value(test_grp::new_private_owner());
+    }
+
+    #[test]
+    #[should_panic]
+    fn tl_acc_uni_owner_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: TLCellAccGrp;
+        }
+        test_grp::new_uniform_owner();
+    }
+
+    #[test]
+    fn tl_acc_pub_owner_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_grp: TLCellAccGrp;
+        }
+        got_value(test_grp::new_public_owner());
+    }
+
+    #[test]
+    fn branching_modules_test() {
+        def_cells! {
+            [pub mod] test_grp0::{test_grp1: TLCellUniGrp};
+            [pub mod] test_grp2::{
+                test_grp3::{test_grp4: TLCellPvtGrp},
+                test_grp5: TLCellPubGrp
+            };
+            [pub mod] test_grp6::{test_grp7::{test_grp8: TLCellAccGrp}};
+        }
+        assert!(test_grp0::test_grp1::has_uniform_access());
+        assert!(test_grp2::test_grp3::test_grp4::has_private_access());
+        assert!(test_grp2::test_grp5::has_public_access());
+        assert_eq!(test_grp6::test_grp7::test_grp8::has_uniform_access(), false);
+    }
+
+    #[test]
+    fn cluster_entry_grammar_tests() {
+        def_cells! {
+            #[allow(dead_code)]
+            [pub mod] test_grp0::{
+                #[cfg(test)] [pub(crate) mod] test_grp1: TCellPubGrp,
+                #[cfg(not(test))] test_grp2: TCellPubGrp,
+                #[doc = "A private cluster"]
+                [pub(crate) mod] test_grp3::{test_grp4: TLCellPvtGrp<const ID: usize>},
+                test_grp5: TLCellUniGrp,
+            };
+        }
+        assert!(test_grp0::test_grp1::has_public_access());
+        assert!(test_grp0::test_grp3::test_grp4::has_private_access());
+        assert!(test_grp0::test_grp5::has_uniform_access());
+
+        let test_cell: test_grp0::test_grp3::test_grp4::PvtCell<1, u8> =
+            test_grp0::test_grp3::test_grp4::new_private_cell(1);
+        assert_eq!(*test_cell.ro(&test_grp0::test_grp3::test_grp4::new_private_owner::<1>()), 1);
+    }
+
+    #[test]
+    fn marker_family_declaration_accuracy_test() {
+        def_cells! {
+            [pub mod] test_shard: TCellUniGrp<const ID: usize>;
+            [pub mod] test_tagged: TLCellAccGrp<Tag, const N: u8>;
+            [pub mod] test_nested::{test_grp: TCellPubGrp<const ID: usize>};
+        }
+        struct TestTag;
+
+        assert_eq!(test_shard::get_cell_impl(), CellImpl::T);
+        assert!(test_tagged::has_private_access() && test_tagged::has_public_access());
+
+        let first_owner = test_shard::new_uniform_owner::<0>();
+        let mut third_owner: test_shard::UniOwner<3> = test_shard::new_uniform_owner();
+        let first_cell: test_shard::UniCell<0, i32> = test_shard::new_uniform_cell(1);
+        let third_cell: test_shard::UniCell<3, i32> = test_shard::new_uniform_cell(2);
+
+        assert_eq!(third_owner.get_self_cell_impl(), CellImpl::T);
+        assert_eq!(third_cell.get_self_cell_role(), CellRoles::Cell);
+
+        *third_cell.rw(&mut third_owner) += *first_cell.ro(&first_owner);
+        assert_eq!(*third_cell.ro(&third_owner), 3);
+        drop(third_owner);
+
+        c_scp! {
+            use test_shard::UniOwner<3> => (
+                third_cell => *cont
+            ) {
+                assert_eq!(cont, 3);
+            }
+        }
+
+        let tagged_owner = test_tagged::new_private_owner::<TestTag, 1>();
+        let tagged_cell: test_tagged::PvtCell<TestTag, 1, &str> = test_tagged::new_private_cell("a");
+        assert_eq!(*tagged_cell.ro(&tagged_owner), "a");
+
+        let nested_cell: test_nested::test_grp::PubCell<2, u8> = test_nested::test_grp::new_public_cell(4);
+        assert_eq!(*nested_cell.ro(&<test_nested::test_grp::PubMarker<2> as CellGroup>::new_owner()), 4);
+    }
+
+    #[test]
+    fn renamed_items_grammar_tests() {
+        def_cells! {
+            [pub mod] test_audio: TCellAccGrp as {
+                pub: (MixerMarker, MixerOwner, MixerCell),
+                pvt: (DeviceMarker, DeviceOwner, DeviceCell),
+            };
+            [pub mod] test_video: TLCellUniGrp as { uni: (FrameMarker, FrameOwner, FrameCell) };
+            [pub mod] test_shard: TCellPubGrp<const ID: usize> as { pub: (ShardMarker, ShardOwner, ShardCell) };
+            [pub mod] test_runtime::{
+                test_q: QCellAccGrp as { pvt: (QueueOwner, QueueCell) },
+                test_l: LCellUniGrp as { uni: (ScopeOwner, ScopeCell) }
+            };
+        }
+        use test_audio::*;
+        use test_video::*;
+
+        let _: (MixerMarker, DeviceMarker, FrameMarker) = (MixerMarker, DeviceMarker, FrameMarker);
 
-        assert_eq!(test_grp::PubCell::<i32>::get_cell_impl(), CellImpl::TL);
-        assert_eq!(test_grp::PubCell::<i32>::get_cell_role(), CellRoles::Cell);
-        assert_eq!(test_grp::PubCell::<i32>::get_access_level(), CellAccessLevels::Public);
+        let mixer_cell: MixerCell<i32> = test_audio::new_public_cell(1);
+        let device_cell: DeviceCell<i32> = DeviceCell::new(2);
+        let frame_cell: FrameCell<i32> = test_video::new_uniform_cell(3);
+        let mixer_owner: MixerOwner = <MixerMarker as CellGroup>::new_owner();
 
-        let test_owner = test_grp::PubOwner::new();
+        assert_eq!(*mixer_cell.ro(&mixer_owner), 1);
+        c_scp! {
+            use _ => (
+                device_cell => *cont
+            ) {
+                assert_eq!(cont, 2);
+            }
+        }
+        c_scp! {
+            let frame_owner = _ => (
+                frame_cell => *cont
+            ) {
+                assert_eq!(cont, 3);
+                let _: &FrameOwner = frame_owner;
+            }
+        }
+        let _: DeviceOwner = test_audio::new_private_owner();
 
-        assert_eq!(test_owner.get_self_cell_impl(), CellImpl::TL);
-        assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
-        assert_eq!(test_owner.get_self_access_level(), CellAccessLevels::Public);
+        let shard_cell: test_shard::ShardCell<4, u8> = test_shard::new_public_cell(4);
+        c_scp! {
+            use test_shard::ShardOwner<4> => (
+                shard_cell => *cont
+            ) {
+                assert_eq!(cont, 4);
+            }
+        }
 
-        let test_cell = test_grp::PubCell::new(1);
+        let queue_owner: test_runtime::test_q::QueueOwner = test_runtime::test_q::new_private_owner();
+        let queue_cell: test_runtime::test_q::QueueCell<u8> = test_runtime::test_q::new_private_cell(&queue_owner, 5);
+        assert_eq!(*queue_cell.ro(&queue_owner), 5);
+        test_runtime::test_l::new_uniform_owner(|scope_owner: test_runtime::test_l::ScopeOwner| {
+            let scope_cell: test_runtime::test_l::ScopeCell<u8> = test_runtime::test_l::new_uniform_cell(6);
+            assert_eq!(*scope_cell.ro(&scope_owner), 6);
+        });
+    }
 
-        assert_eq!(test_cell.get_self_cell_impl(), CellImpl::TL);
-        assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
-        assert_eq!(test_cell.get_self_access_level(), CellAccessLevels::Public);
+    #[test]
+    fn newtype_cell_grammar_tests() {
+        def_cells! {
+            [pub mod] test_grp: TCellAccGrp with { cells: newtype };
+            [pub mod] test_tl_grp: TLCellUniGrp as { uni: (TestMarker, TestOwner, TestCell) } with { cells: newtype };
+            [pub mod] test_shard: TCellUniGrp<const ID: usize> with { cells: newtype };
+        }
 
-        assert_eq!(test_grp::PvtMarker::get_cell_impl(), CellImpl::TL);
-        assert_eq!(test_grp::PvtMarker::get_cell_role(), CellRoles::Marker);
-        assert_eq!(test_grp::PvtMarker::get_access_level(), CellAccessLevels::Private);
+        trait Describe {
+            fn describe(&self) -> &'static str;
+        }
+        impl<T> Describe for test_grp::PubCell<T> {
+            fn describe(&self) -> &'static str {
+                "public"
+            }
+        }
 
-        assert_eq!(test_grp::PvtOwner::get_cell_impl(), CellImpl::TL);
-        assert_eq!(test_grp::PvtOwner::get_cell_role(), CellRoles::Owner);
-        assert_eq!(test_grp::PvtOwner::get_access_level(), CellAccessLevels::Private);
+        let pub_cell: test_grp::PubCell<i32> = 1.into();
+        let pvt_cell: test_grp::PvtCell<Vec<u8>> = Default::default();
+        assert_eq!(pub_cell.describe(), "public");
+        assert_eq!(pub_cell.get_self_cell_role(), CellRoles::Cell);
+        assert_eq!(pub_cell.get_self_cell_impl(), CellImpl::T);
 
-        assert_eq!(test_grp::PvtCell::<i32>::get_cell_impl(), CellImpl::TL);
-        assert_eq!(test_grp::PvtCell::<i32>::get_cell_role(), CellRoles::Cell);
-        assert_eq!(test_grp::PvtCell::<i32>::get_access_level(), CellAccessLevels::Private);
+        c_scp! {
+            use _ => (
+                pub_cell => mut cont
+            ) {
+                *cont += 1;
+            }
+        }
+        c_scp! {
+            let pvt_owner = test_grp::PvtOwner => (
+                pvt_cell => mut cont
+            ) {
+                cont.push(2);
+                let _: &mut test_grp::PvtOwner = pvt_owner;
+            }
+        }
+        assert_eq!(pub_cell.into_inner(), 2);
+        assert_eq!(pvt_cell.as_inner().ro(&test_grp::new_private_owner()), &[2]);
+
+        let mut test_cell = test_tl_grp::TestCell::new(3);
+        *test_cell.get_mut() += 1;
+        let dyn_cell: Box<test_tl_grp::TestCell<dyn Describe>> =
+            Box::new(test_tl_grp::TestCell::new(test_grp::PubCell::new(())));
+        c_scp! {
+            let test_owner = test_tl_grp::TestOwner => (
+                test_cell => *cont
+            ) {
+                assert_eq!(cont, 4);
+                assert_eq!(dyn_cell.ro(test_owner).describe(), "public");
+            }
+        }
 
-        let test_owner = test_grp::PvtOwner::new();
+        let shard_cell: test_shard::UniCell<5, u8> = test_shard::new_uniform_cell(5);
+        c_scp! {
+            use _ => (
+                shard_cell => *cont
+            ) {
+                assert_eq!(cont, 5);
+            }
+        }
+    }
 
-        assert_eq!(test_owner.get_self_cell_impl(), CellImpl::TL);
-        assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
-        assert_eq!(test_owner.get_self_access_level(), CellAccessLevels::Private);
+    static TEST_ACQUIRED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    static TEST_RELEASED: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
 
-        let test_cell = test_grp::PvtCell::new(1);
+    struct TestHooks;
 
-        assert_eq!(test_cell.get_self_cell_impl(), CellImpl::TL);
-        assert_eq!(test_cell.get_self_cell_role(), CellRoles::Cell);
-        assert_eq!(test_cell.get_self_access_level(), CellAccessLevels::Private);
+    impl OwnerHooks for TestHooks {
+        fn on_acquire(group: &'static str) {
+            assert!(group.ends_with("test_metered"));
+            TEST_ACQUIRED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+        fn on_release(group: &'static str) {
+            assert!(group.ends_with("test_metered"));
+            TEST_RELEASED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
     }
 
     #[test]
-    fn tl_acc_pvt_owner_declaration_accuracy_test() {
+    fn newtype_owner_grammar_tests() {
+        use std::sync::atomic::Ordering;
+
         def_cells! {
-            [pub mod] test_grp: TLCellAccGrp;
+            [pub mod] test_metered: TCellUniGrp with { owners: newtype, hooks: (super::TestHooks) };
+            [pub mod] test_plain: TLCellAccGrp with { owners: newtype, cells: newtype };
+            [pub mod] test_shard: TCellPubGrp<const ID: usize> with { owners: newtype };
+        }
+
+        let test_cell = test_metered::new_uniform_cell(1);
+        {
+            let mut test_owner = test_metered::new_uniform_owner();
+            assert_eq!(test_owner.get_self_cell_role(), CellRoles::Owner);
+            assert_eq!(test_owner.get_self_cell_impl(), CellImpl::T);
+            *test_cell.rw(&mut test_owner) += 1;
+            c_scp! {
+                use &mut test_owner => (
+                    test_cell => mut cont
+                ) {
+                    *cont += 1;
+                }
+            }
+            assert_eq!(TEST_ACQUIRED.load(Ordering::SeqCst), 1);
+            assert_eq!(TEST_RELEASED.load(Ordering::SeqCst), 0);
+        }
+        c_scp! {
+            use _ => (
+                test_cell => *cont
+            ) {
+                assert_eq!(cont, 3);
+            }
+        }
+        assert_eq!(TEST_ACQUIRED.load(Ordering::SeqCst), 2);
+        assert_eq!(TEST_RELEASED.load(Ordering::SeqCst), 2);
+
+        let pvt_owner = test_plain::PvtOwner::new();
+        assert!(test_plain::PvtOwner::GROUP.ends_with("test_plain"));
+        assert_eq!(
+            format!("{:?}", pvt_owner),
+            format!("PvtOwner {{ group: {:?} }}", test_plain::PvtOwner::GROUP)
+        );
+        let pvt_cell: test_plain::PvtCell<i32> = 4.into();
+        assert_eq!(*pvt_cell.ro(&pvt_owner), 4);
+        assert_eq!(*pvt_owner.ro(pvt_cell.as_inner()), 4);
+
+        let shard_cell: test_shard::PubCell<1, i32> = test_shard::new_public_cell(5);
+        c_scp! {
+            use test_shard::PubOwner<1> => (
+                shard_cell => *cont
+            ) {
+                assert_eq!(cont, 5);
+            }
         }
-        got_value(test_grp::new_private_owner());
     }
 
     #[test]
-    #[should_panic]
-    fn tl_acc_uni_owner_declaration_accuracy_test() {
+    fn owner_type_grammar_tests() {
         def_cells! {
-            [pub mod] test_grp: TLCellAccGrp;
+            [mod] test_grp: TCellUniGrp;
+            [mod] test_shard: TLCellUniGrp<const ID: usize>;
+        }
+
+        fn bump<G>(cell: &qcell::TCell<G, i32>)
+        where
+            G: CellGroup<Owner = qcell::TCellOwner<G>> + 'static
+        {
+            c_scp! {
+                use <G as CellGroup>::Owner => (
+                    cell => mut cont
+                ) {
+                    *cont += 1;
+                }
+            }
+            c_scp! {
+                let test_owner = (G::new_owner()) => (
+                    cell => mut cont
+                ) {
+                    *cont += 1;
+                    let _: &mut qcell::TCellOwner<G> = test_owner;
+                }
+            }
+        }
+
+        let test_cell = test_grp::new_uniform_cell(1);
+        bump(&test_cell);
+        c_scp! {
+            use test_grp::UniOwner => (
+                test_cell => *cont
+            ) {
+                assert_eq!(cont, 3);
+            }
+        }
+
+        let shard_cell: test_shard::UniCell<2, i32> = test_shard::new_uniform_cell(4);
+        c_scp! {
+            use (test_shard::new_uniform_owner::<2>()) => (
+                shard_cell => *mut cont
+            ) {
+                cont += 1;
+                assert_eq!(cont, 5);
+            }
+        }
+        c_scp! {
+            use <test_shard::UniMarker<2> as CellGroup>::Owner => (
+                shard_cell => *cont
+            ) {
+                assert_eq!(cont, 4);
+            }
         }
-        test_grp::new_uniform_owner();
     }
 
     #[test]
-    fn tl_acc_pub_owner_declaration_accuracy_test() {
+    fn shared_cell_grammar_tests() {
         def_cells! {
-            [pub mod] test_grp: TLCellAccGrp;
+            [mod] test_grp: TCellAccGrp;
+            [mod] test_shard: TLCellUniGrp<const ID: usize>;
+            [mod] test_renamed: TCellUniGrp as {
+                uni: (TestMarker, TestOwner, TestCell, RcTestCell, ArcTestCell, WeakTestCell)
+            };
+            [mod] test_q_grp: QCellUniGrp;
+            [mod] test_l_grp: LCellPubGrp;
+        }
+
+        let rc_cell: test_grp::RcPubCell<i32> = test_grp::new_rc_public_cell(1);
+        let weak_cell: test_grp::WeakPubCell<i32> = std::rc::Rc::downgrade(&rc_cell);
+        let arc_cell: test_grp::ArcPvtCell<Vec<u8>> = test_grp::new_arc_private_cell(vec![]);
+        c_scp! {
+            use _ => (
+                weak weak_cell => mut cont
+            ) {
+                *cont += 1;
+            }
         }
-        got_value(test_grp::new_public_owner());
+        c_scp! {
+            let pvt_owner = test_grp::PvtOwner => (
+                arc_cell => mut cont
+            ) {
+                cont.push(2);
+                let _: &mut test_grp::PvtOwner = pvt_owner;
+            }
+        }
+        assert_eq!(*rc_cell.ro(&test_grp::new_public_owner()), 2);
+        assert_eq!(arc_cell.ro(&test_grp::new_private_owner()), &[2]);
+
+        drop(rc_cell);
+        let mut ran = false;
+        c_scp! {
+            use test_grp::PubOwner => (
+                weak weak_cell => *cont
+            ) {
+                ran = cont > 0;
+            }
+        }
+        assert!(!ran);
+
+        let shard_cell: test_shard::RcUniCell<3, u8> = test_shard::new_rc_uniform_cell(3);
+        let renamed_cell: test_renamed::RcTestCell<u8> = test_renamed::new_rc_uniform_cell(4);
+        let weak_renamed: test_renamed::WeakTestCell<u8> = std::rc::Rc::downgrade(&renamed_cell);
+        c_scp! {
+            use _ => (
+                shard_cell => *cont
+            ) {
+                assert_eq!(cont, 3);
+            }
+        }
+        c_scp! {
+            let test_owner = test_renamed::TestOwner => (
+                weak weak_renamed => mut cont
+            ) {
+                *cont += 1;
+                let _: &mut test_renamed::TestOwner = test_owner;
+            }
+        }
+        assert_eq!(*renamed_cell.ro(&test_renamed::TestOwner::new()), 5);
+
+        let mut q_owner = test_q_grp::new_uniform_owner();
+        let q_cell: test_q_grp::RcUniCell<i32> = test_q_grp::new_rc_uniform_cell(&q_owner, 6);
+        let q_weak: test_q_grp::WeakUniCell<i32> = std::rc::Rc::downgrade(&q_cell);
+        c_scp! {
+            use &mut q_owner => (
+                weak q_weak => mut cont
+            ) {
+                *cont += 1;
+            }
+        }
+        assert_eq!(*q_cell.ro(&q_owner), 7);
+
+        test_l_grp::new_public_owner(|l_owner| {
+            let l_cell: test_l_grp::ArcPubCell<'_, i32> = test_l_grp::new_arc_public_cell(8);
+            assert_eq!(*l_cell.ro(&l_owner), 8);
+        });
     }
 
     #[test]
-    fn branching_modules_test() {
+    fn runtime_c_scp_grammar_tests() {
         def_cells! {
-            [pub mod] test_grp0::{test_grp1: TLCellUniGrp};
-            [pub mod] test_grp2::{
-                test_grp3::{test_grp4: TLCellPvtGrp},
-                test_grp5: TLCellPubGrp
-            };
-            [pub mod] test_grp6::{test_grp7::{test_grp8: TLCellAccGrp}};
+            [mod] test_q_grp: QCellUniGrp;
+            [mod] test_l_grp::{test_l_pub_grp: LCellPubGrp};
+        }
+
+        let mut q_owner = test_q_grp::new_uniform_owner();
+        let q_cell = test_q_grp::new_uniform_cell(&q_owner, 5);
+        c_scp! {
+            use &mut q_owner => (
+                q_cell => mut q_cont
+            ) {
+                *q_cont += 1;
+                assert_eq!(*q_cont, 6);
+            }
         }
-        assert!(test_grp0::test_grp1::has_uniform_access());
-        assert!(test_grp2::test_grp3::test_grp4::has_private_access());
-        assert!(test_grp2::test_grp5::has_public_access());
-        assert_eq!(test_grp6::test_grp7::test_grp8::has_uniform_access(), false);
+        c_scp! {
+            use &q_owner => (
+                q_cell => * q_cont
+            ) {
+                assert_eq!(q_cont, 6);
+            }
+        }
+
+        test_l_grp::test_l_pub_grp::new_public_owner(|mut l_owner| {
+            let l_cell = test_l_grp::test_l_pub_grp::new_public_cell(5);
+            c_scp! {
+                use &mut l_owner => (
+                    l_cell => *mut l_cont
+                ) {
+                    l_cont += 1;
+                    assert_eq!(l_cont, 6);
+                }
+            }
+            c_scp! {
+                use &l_owner => (
+                    l_cell => & l_cont
+                ) {
+                    assert_eq!(l_cont, &5);
+                }
+            }
+        });
     }
 
     #[test]
@@ -4271,6 +7163,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(dead_code)]
     fn individual_builder_grammar_tests() {
         new_t_marker_type!(pub TestTMarkerA);
         new_t_cell_type!(pub TestTCellA<T>[TestTMarkerA]);
@@ -4347,6 +7240,282 @@ mod tests {
         }
     }
 
+    #[test]
+    fn families_access_grammar_tests() {
+        new_t_group!(pvt TestPvtOwnerT[TestPvtMarkerT] => TestPvtCellT<T>);
+        new_tl_group!(pub pub TestPubOwnerTL[TestPubMarkerTL] => TestPubCellTL<T>);
+        new_t_group! {
+            access: public,
+            pub marker: TestPubMarkerT,
+            owner: TestPubOwnerT,
+            cell: TestPubCellT<T>,
+        }
+        new_tl_group! {
+            marker: TestUniMarkerTL,
+            cell: TestUniCellTL,
+            access: uniform,
+            owner: TestUniOwnerTL
+        }
+        new_tl_marker_type!(pvt TestPvtMarkerTL);
+        new_tl_owner_type!(TestPvtOwnerTL[TestPvtMarkerTL]);
+
+        assert_eq!(TestPvtMarkerT::get_access_level(), CellAccessLevels::Private);
+        assert_eq!(TestPvtCellT::<i32>::get_access_level(), CellAccessLevels::Private);
+        assert_eq!(TestPubOwnerTL::get_access_level(), CellAccessLevels::Public);
+        assert_eq!(TestPubCellT::<i32>::get_access_level(), CellAccessLevels::Public);
+        assert_eq!(TestUniOwnerTL::get_access_level(), CellAccessLevels::Uniform);
+        assert_eq!(TestPvtOwnerTL::get_access_level(), CellAccessLevels::Private);
+
+        struct MyStruct {
+            pvt_cell: TestPvtCellT<i32>,
+            pub_cell: TestPubCellTL<i32>,
+            uni_cell: TestUniCellTL<i32>,
+        }
+
+        impl MyStruct {
+            fn do_test(&self) {
+                c_scp! {
+                    use [self] => (
+                        self.pvt_cell => mut test_cont
+                    ) {
+                        *test_cont += 1;
+                    }
+                }
+                c_scp! {
+                    use [self] => (
+                        self.pub_cell => *mut test_cont
+                    ) {
+                        test_cont += 1;
+                        assert_eq!(test_cont, 3);
+                    }
+                }
+                c_scp! {
+                    use _ => (
+                        self.uni_cell => *test_cont
+                    ) {
+                        assert_eq!(test_cont, 3);
+                    }
+                }
+                c_scp! {
+                    use _ => (
+                        self.pvt_cell => *test_cont
+                    ) {
+                        assert_eq!(test_cont, 2);
+                    }
+                }
+            }
+        }
+
+        impl_get_pvt!(MyStruct => TestPvtOwnerT);
+        impl_get_pub!(MyStruct => TestPubOwnerTL);
+
+        let test_struct = MyStruct {
+            pvt_cell: TestPvtCellT::new(1),
+            pub_cell: TestPubCellTL::new(2),
+            uni_cell: TestUniCellTL::new(3),
+        };
+
+        test_struct.do_test();
+        let _ = (TestPubMarkerT, TestPubOwnerT::new());
+    }
+
+    #[test]
+    fn families_owner_grammar_tests() {
+        new_t_marker_type!(TestMarkerA);
+        new_t_owner_type!(TestOwnerA[TestMarkerA]);
+        new_t_cell_type!(TestCellA<T>[TestMarkerA], owner: TestOwnerA);
+
+        new_tl_marker_type!(pvt TestMarkerB);
+        new_tl_owner_type!(TestOwnerB[TestMarkerB]);
+        new_tl_cell_type!(TestCellB[TestMarkerB], owner: TestOwnerB);
+        new_tl_cell_type!(TestOtherCellB[TestMarkerB]);
+
+        struct MyStruct {
+            uni_cell: TestCellA<i32>,
+            pvt_cell: TestOtherCellB<i32>,
+        }
+
+        impl MyStruct {
+            fn do_test(&self) {
+                c_scp! {
+                    use _ => (
+                        self.uni_cell => mut test_cont
+                    ) {
+                        *test_cont += 1;
+                        assert_eq!(*test_cont, 2);
+                    }
+                }
+                c_scp! {
+                    use [self] => (
+                        self.pvt_cell => *test_cont
+                    ) {
+                        assert_eq!(test_cont, 5);
+                    }
+                }
+            }
+        }
+
+        impl_get_pvt!(MyStruct => TestOwnerB);
+
+        let test_struct = MyStruct {
+            uni_cell: TestCellA::new(1),
+            pvt_cell: TestOtherCellB::new(5),
+        };
+
+        test_struct.do_test();
+
+        let test_cell = TestCellB::new(3);
+        c_scp! {
+            use _ => (
+                test_cell => *test_cont
+            ) {
+                assert_eq!(test_cont, 3);
+            }
+        }
+    }
+
+    #[test]
+    fn generic_cell_grammar_tests() {
+        use std::collections::HashMap;
+        use std::hash::Hash;
+        use std::rc::Rc;
+
+        trait Handler {
+            fn handle(&self) -> i32;
+            fn bump(&mut self);
+        }
+
+        impl Handler for i32 {
+            fn handle(&self) -> i32 {
+                *self
+            }
+
+            fn bump(&mut self) {
+                *self += 1;
+            }
+        }
+
+        new_tl_marker_type!(TestMarkerA);
+        new_tl_owner_type!(TestOwnerA[TestMarkerA]);
+        new_tl_cell_type!(pub Slot<'a, K: Hash, V: ?Sized>[TestMarkerA] => (&'a K, Box<V>));
+        new_tl_cell_type!(Table<V, K: Into<String>>[TestMarkerA] => HashMap<K, V>, owner: TestOwnerA);
+        new_tl_cell_type!(Dyn<T: ?Sized>[TestMarkerA]);
+
+        new_t_marker_type!(TestMarkerB);
+        new_t_owner_type!(TestOwnerB[TestMarkerB]);
+        new_t_cell_type!(Pair<A, B>[TestMarkerB] => (A, B), owner: TestOwnerB);
+
+        def_cells! {
+            [mod] test_uni_grp: TLCellUniGrp;
+        }
+
+        let key = 1;
+        let slot: Slot<i32, dyn Handler> = Slot::new((&key, Box::new(2)));
+        let table: Table<i32, &str> = Table::new(HashMap::new());
+        let dyn_cell: Box<Dyn<dyn Handler>> = Box::new(Dyn::new(3));
+        let pair: Pair<i32, u8> = Pair::new((4, 5));
+        let uni_cell: Rc<test_uni_grp::UniCell<dyn Handler>> = Rc::new(test_uni_grp::UniCell::new(6));
+
+        let mut owner = TestOwnerA::new();
+        assert_eq!(slot.ro(&owner).1.handle(), 2);
+        table.rw(&mut owner).insert("a", 1);
+
+        c_scp! {
+            use &mut owner => (
+                dyn_cell => mut test_cont
+            ) {
+                test_cont.bump();
+            }
+        }
+        c_scp! {
+            use &owner => (
+                dyn_cell => & test_cont
+            ) {
+                assert_eq!(test_cont.handle(), 4);
+            }
+        }
+        drop(owner);
+        c_scp! {
+            use _ => (
+                table => test_cont
+            ) {
+                assert_eq!(test_cont.get("a"), Some(&1));
+            }
+        }
+        c_scp! {
+            use _ => (
+                pair => *test_cont
+            ) {
+                assert_eq!(test_cont, (4, 5));
+            }
+        }
+        c_scp! {
+            let test_owner = _ => (
+                mut uni_cell
+            ) {
+                uni_cell.rw(test_owner).bump();
+            }
+        }
+        c_scp! {
+            use test_uni_grp::UniOwner => (
+                uni_cell => test_cont
+            ) {
+                assert_eq!(test_cont.handle(), 7);
+            }
+        }
+    }
+
+    #[test]
+    fn unified_group_grammar_tests() {
+        new_group!(impl = T, #[allow(dead_code)] TestOwnerA[TestMarkerA] => TestCellA<T>);
+        new_group!(impl = TL, access = pub, #[allow(dead_code)] pub TestOwnerB[TestMarkerB] => TestCellB);
+        new_group!(impl = T, access = pvt, TestOwnerC[TestMarkerC] => TestCellC<U>);
+        new_group! {
+            impl = TL,
+            access: private,
+            marker: TestMarkerD,
+            owner: TestOwnerD,
+            cell: TestCellD<T>
+        }
+        new_group!(impl = Q, access = pub, TestOwnerE[TestMarkerE] => TestCellE<T>);
+        new_group!(impl = L, TestOwnerF[TestMarkerF] => TestCellF);
+        new_group!(impl = auto, access = pvt, TestOwnerG[TestMarkerG] => TestCellG<T>);
+
+        assert_eq!(TestMarkerA::get_cell_impl(), CellImpl::T);
+        assert_eq!(TestMarkerA::get_access_level(), CellAccessLevels::Uniform);
+        assert_eq!(TestOwnerB::get_cell_impl(), CellImpl::TL);
+        assert_eq!(TestCellB::<i32>::get_access_level(), CellAccessLevels::Public);
+        assert_eq!(TestCellC::<i32>::get_access_level(), CellAccessLevels::Private);
+        assert_eq!(TestOwnerD::get_access_level(), CellAccessLevels::Private);
+        assert_eq!(TestCellE::<i32>::get_cell_impl(), CellImpl::Q);
+        assert_eq!(TestOwnerF::get_cell_impl(), CellImpl::L);
+        assert_eq!(TestMarkerG::get_access_level(), CellAccessLevels::Private);
+        if cfg!(feature = "auto-tl") {
+            assert_eq!(TestCellG::<i32>::get_cell_impl(), CellImpl::TL);
+        } else {
+            assert_eq!(TestCellG::<i32>::get_cell_impl(), CellImpl::T);
+        }
+
+        let test_cell = TestCellD::new(1);
+        c_scp! {
+            use _ => (
+                test_cell => *mut test_cont
+            ) {
+                test_cont += 1;
+                assert_eq!(test_cont, 2);
+            }
+        }
+
+        let q_owner = TestOwnerE::new();
+        let q_cell: TestCellE<i32> = q_owner.cell(3);
+        assert_eq!(*q_cell.ro(&q_owner), 3);
+
+        TestOwnerF::scope(|l_owner| {
+            let l_cell: TestCellF<i32> = TestCellF::new(4);
+            assert_eq!(*l_cell.ro(&l_owner), 4);
+        });
+    }
+
     #[test]
     fn default_impl_grammar_tests() {
         def_cells! {
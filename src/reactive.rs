@@ -0,0 +1,622 @@
+//! A small reactive layer, whose values live in the cells of one group.
+//!
+//! A [`Reactor`] makes [`Signal`]s, which hold values that are set through
+//! the group's owner, and [`Derived`] values, which are computed from
+//! signals and other derived values. `G` is the marker of a [`TCell`] or
+//! [`TLCell`] group, such as `my_grp::UniMarker`.
+//!
+//! A derived value records what it read while it was computed, through a
+//! [`Reader`] that holds the owner, and is only computed again when it is
+//! read after one of those has changed. Since every read and write goes
+//! through the same owner, nothing can observe a derived value while the
+//! signals it depends on are half-updated:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::reactive::Reactor;
+//! use std::rc::Rc;
+//! use std::cell::Cell;
+//!
+//! def_cells! {
+//!     [pub mod] ui: TLCellUniGrp;
+//! }
+//!
+//! let mut owner = ui::new_uniform_owner();
+//! let reactor: Reactor<ui::UniMarker> = Reactor::new();
+//! let first = reactor.signal(String::from("Ada"));
+//! let last = reactor.signal(String::from("Byron"));
+//! let full = reactor.derived({
+//!     let (first, last) = (first.clone(), last.clone());
+//!     move |reader| {
+//!         let first = reader.get(&first).clone();
+//!         format!("{} {}", first, reader.get(&last))
+//!     }
+//! });
+//! assert_eq!(full.get(&mut owner), "Ada Byron");
+//!
+//! let updates = Rc::new(Cell::new(0));
+//! let counted = updates.clone();
+//! full.subscribe(&mut owner, move |_| counted.set(counted.get() + 1));
+//!
+//! reactor.write(&mut owner, |owner| {
+//!     first.set(owner, String::from("Augusta"));
+//!     last.set(owner, String::from("King"));
+//! });
+//! assert_eq!(updates.get(), 1);
+//! assert_eq!(full.get(&mut owner), "Augusta King");
+//! ```
+//!
+//! Subscribers are called with the owner once a [`write`] scope ends, or
+//! when the reactor is [`flush`]ed, and never in the middle of a scope.
+//! Writes that subscribers make are handled before the flush returns.
+//!
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+//! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+//! [`write`]: ./struct.Reactor.html#method.write
+//! [`flush`]: ./struct.Reactor.html#method.flush
+
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::collections::CellOf;
+use crate::{CellGroup, GroupOwner};
+
+type Subscriber<O> = Box<dyn FnMut(&mut O)>;
+
+// A signal or derived value, as seen by the values which depend on it.
+trait Node<O> {
+    // Brings the node up to date, and returns its version.
+    fn refresh(&self, owner : &mut O) -> u64;
+    // Calls the node's subscribers, if it has changed since they were last
+    // called.
+    fn notify(&self, owner : &mut O);
+}
+
+type Dependencies<O> = Vec<(Rc<dyn Node<O>>, u64)>;
+
+type Compute<G, T> = Box<dyn Fn(&mut Reader<'_, G>) -> T>;
+
+struct Pending<O> {
+    changed : Vec<Rc<dyn Node<O>>>,
+    watched : Vec<Weak<dyn Node<O>>>
+}
+
+// The state of a node which keeps subscribers.
+trait Subscribed<O> {
+    fn subscribers(&mut self) -> &mut Vec<Subscriber<O>>;
+}
+
+// Runs every subscriber of `state` with the owner, and then puts them back
+// in front of any which were added while they ran.
+fn run_subscribers<G, S>(owner : &mut G::Owner, state : &CellOf<G, S>)
+where
+    G : CellGroup,
+    G::Owner : GroupOwner,
+    S : Subscribed<G::Owner>
+{
+    let mut running = mem::take(owner.rw(state).subscribers());
+    for subscriber in running.iter_mut() {
+        subscriber(owner);
+    }
+    let added = owner.rw(state).subscribers();
+    running.append(added);
+    *added = running;
+}
+
+/// Makes the [`Signal`]s and [`Derived`] values of the group `G`, and
+/// calls their subscribers when they change.
+///
+/// [`Signal`]: ./struct.Signal.html
+/// [`Derived`]: ./struct.Derived.html
+pub struct Reactor<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    pending : Rc<CellOf<G, Pending<G::Owner>>>
+}
+
+impl<G> Reactor<G>
+where
+    G : CellGroup + 'static,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self { pending : Rc::new(G::Owner::new_cell(Pending { changed : Vec::new(), watched : Vec::new() })) }
+    }
+
+    /// Creates a [`Signal`] holding `value`.
+    ///
+    /// [`Signal`]: ./struct.Signal.html
+    pub fn signal<T : 'static>(&self, value : T) -> Signal<G, T> {
+        let state = SignalState { value, version : 1, queued : false, subscribers : Vec::new() };
+        Signal {
+            node : Rc::new(SignalNode { state : G::Owner::new_cell(state), pending : self.pending.clone() })
+        }
+    }
+
+    /// Creates a [`Derived`] value, which is computed by `compute` when it
+    /// is first read, and again whenever it is read after something that
+    /// `compute` read through its [`Reader`] has changed.
+    ///
+    /// [`Derived`]: ./struct.Derived.html
+    /// [`Reader`]: ./struct.Reader.html
+    pub fn derived<T, F>(&self, compute : F) -> Derived<G, T>
+    where
+        T : 'static,
+        F : Fn(&mut Reader<'_, G>) -> T + 'static
+    {
+        let state = DerivedState {
+            value : None,
+            version : 0,
+            notified : 0,
+            computing : false,
+            dependencies : Vec::new(),
+            subscribers : Vec::new()
+        };
+        Derived {
+            node : Rc::new(DerivedNode {
+                state : G::Owner::new_cell(state),
+                compute : Box::new(compute),
+                pending : self.pending.clone()
+            })
+        }
+    }
+
+    /// Runs `scope` with the owner, and then [`flush`]es the reactor, so
+    /// that subscribers only see the values that `scope` left behind.
+    ///
+    /// [`flush`]: #method.flush
+    pub fn write<R>(&self, owner : &mut G::Owner, scope : impl FnOnce(&mut G::Owner) -> R) -> R {
+        let result = scope(owner);
+        self.flush(owner);
+        result
+    }
+
+    /// Calls the subscribers of every signal that was set, and of every
+    /// derived value that has changed, since the last flush.
+    pub fn flush(&self, owner : &mut G::Owner) {
+        loop {
+            let changed = mem::take(&mut owner.rw(&*self.pending).changed);
+            for node in &changed {
+                node.notify(owner);
+            }
+            let pending = owner.rw(&*self.pending);
+            pending.watched.retain(|node| node.strong_count() > 0);
+            let watched : Vec<_> = pending.watched.iter().filter_map(Weak::upgrade).collect();
+            for node in &watched {
+                node.notify(owner);
+            }
+            if owner.ro(&*self.pending).changed.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+impl<G> Default for Reactor<G>
+where
+    G : CellGroup + 'static,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SignalState<O, T> {
+    value : T,
+    version : u64,
+    queued : bool,
+    subscribers : Vec<Subscriber<O>>
+}
+
+impl<O, T> Subscribed<O> for SignalState<O, T> {
+    #[inline]
+    fn subscribers(&mut self) -> &mut Vec<Subscriber<O>> {
+        &mut self.subscribers
+    }
+}
+
+struct SignalNode<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    state : CellOf<G, SignalState<G::Owner, T>>,
+    pending : Rc<CellOf<G, Pending<G::Owner>>>
+}
+
+impl<G, T> Node<G::Owner> for SignalNode<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn refresh(&self, owner : &mut G::Owner) -> u64 {
+        owner.ro(&self.state).version
+    }
+
+    fn notify(&self, owner : &mut G::Owner) {
+        owner.rw(&self.state).queued = false;
+        run_subscribers::<G, _>(owner, &self.state);
+    }
+}
+
+/// A value in a cell of the group `G`, which is read and set through the
+/// group's owner.
+///
+/// Cloning a `Signal` gives another handle to the same value.
+pub struct Signal<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    node : Rc<SignalNode<G, T>>
+}
+
+impl<G, T> Signal<G, T>
+where
+    G : CellGroup + 'static,
+    G::Owner : GroupOwner,
+    T : 'static
+{
+    #[inline]
+    pub fn get<'a>(&'a self, owner : &'a G::Owner) -> &'a T {
+        &owner.ro(&self.node.state).value
+    }
+
+    /// Replaces the value, and queues the signal's subscribers until the
+    /// reactor is next flushed. Setting the value that the signal already
+    /// holds does nothing.
+    #[inline]
+    pub fn set(&self, owner : &mut G::Owner, value : T)
+    where
+        T : PartialEq
+    {
+        if *self.get(owner) != value {
+            self.update(owner, |current| *current = value);
+        }
+    }
+
+    /// Changes the value in place, and queues the signal's subscribers
+    /// until the reactor is next flushed, whether or not the value has
+    /// changed.
+    pub fn update<R>(&self, owner : &mut G::Owner, change : impl FnOnce(&mut T) -> R) -> R {
+        let state = owner.rw(&self.node.state);
+        let result = change(&mut state.value);
+        state.version += 1;
+        if !mem::replace(&mut state.queued, true) {
+            let node : Rc<dyn Node<G::Owner>> = self.node.clone();
+            owner.rw(&*self.node.pending).changed.push(node);
+        }
+        result
+    }
+
+    /// Returns the version of the value, which grows each time it is set to
+    /// a different value or updated.
+    #[inline]
+    pub fn version(&self, owner : &G::Owner) -> u64 {
+        owner.ro(&self.node.state).version
+    }
+
+    /// Adds a subscriber, which is called with the owner after the signal
+    /// has been set.
+    pub fn subscribe(&self, owner : &mut G::Owner, subscriber : impl FnMut(&mut G::Owner) + 'static) {
+        owner.rw(&self.node.state).subscribers.push(Box::new(subscriber));
+    }
+}
+
+impl<G, T> Clone for Signal<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { node : self.node.clone() }
+    }
+}
+
+struct DerivedState<O, T> {
+    value : Option<T>,
+    version : u64,
+    notified : u64,
+    computing : bool,
+    dependencies : Dependencies<O>,
+    subscribers : Vec<Subscriber<O>>
+}
+
+impl<O, T> Subscribed<O> for DerivedState<O, T> {
+    #[inline]
+    fn subscribers(&mut self) -> &mut Vec<Subscriber<O>> {
+        &mut self.subscribers
+    }
+}
+
+struct DerivedNode<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    state : CellOf<G, DerivedState<G::Owner, T>>,
+    compute : Compute<G, T>,
+    pending : Rc<CellOf<G, Pending<G::Owner>>>
+}
+
+impl<G, T> Node<G::Owner> for DerivedNode<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    fn refresh(&self, owner : &mut G::Owner) -> u64 {
+        let state = owner.ro(&self.state);
+        assert!(!state.computing, "a derived value cannot depend on itself");
+        let dependencies = state.dependencies.clone();
+        let stale = state.value.is_none()
+            || dependencies.iter().any(|(node, seen)| node.refresh(owner) != *seen);
+        if stale {
+            owner.rw(&self.state).computing = true;
+            let mut computing = Computing {
+                reader : Reader { owner : &mut *owner, dependencies : Vec::new() },
+                state : &self.state
+            };
+            let value = (self.compute)(&mut computing.reader);
+            let dependencies = mem::take(&mut computing.reader.dependencies);
+            drop(computing);
+            let state = owner.rw(&self.state);
+            state.value = Some(value);
+            state.dependencies = dependencies;
+            state.version += 1;
+        }
+        owner.ro(&self.state).version
+    }
+
+    fn notify(&self, owner : &mut G::Owner) {
+        let version = self.refresh(owner);
+        if mem::replace(&mut owner.rw(&self.state).notified, version) != version {
+            run_subscribers::<G, _>(owner, &self.state);
+        }
+    }
+}
+
+// The reader of a derived value which is being computed. Dropping it clears
+// the value's `computing` flag, even when the computation panics, so that a
+// later read is not taken for a cycle.
+struct Computing<'a, G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    reader : Reader<'a, G>,
+    state : &'a CellOf<G, DerivedState<G::Owner, T>>
+}
+
+impl<G, T> Drop for Computing<'_, G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn drop(&mut self) {
+        self.reader.owner.rw(self.state).computing = false;
+    }
+}
+
+/// A value of the group `G` which is computed from [`Signal`]s and other
+/// `Derived` values, and kept until one of them changes.
+///
+/// Cloning a `Derived` gives another handle to the same value.
+///
+/// [`Signal`]: ./struct.Signal.html
+pub struct Derived<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    node : Rc<DerivedNode<G, T>>
+}
+
+impl<G, T> Derived<G, T>
+where
+    G : CellGroup + 'static,
+    G::Owner : GroupOwner,
+    T : 'static
+{
+    /// Returns the value, after computing it again if anything that it
+    /// depends on has changed.
+    pub fn get<'a>(&'a self, owner : &'a mut G::Owner) -> &'a T {
+        self.node.refresh(owner);
+        owner.ro(&self.node.state).value.as_ref().expect("the derived value was computed")
+    }
+
+    /// Returns the version of the value, which grows each time that it is
+    /// computed. This does not compute it again.
+    #[inline]
+    pub fn version(&self, owner : &G::Owner) -> u64 {
+        owner.ro(&self.node.state).version
+    }
+
+    /// Adds a subscriber, which is called with the owner when the reactor
+    /// is flushed, if the value has changed since it was last called.
+    pub fn subscribe(&self, owner : &mut G::Owner, subscriber : impl FnMut(&mut G::Owner) + 'static) {
+        let version = self.node.refresh(owner);
+        let state = owner.rw(&self.node.state);
+        state.notified = version;
+        if state.subscribers.is_empty() {
+            let node : Rc<dyn Node<G::Owner>> = self.node.clone();
+            owner.rw(&*self.node.pending).watched.push(Rc::downgrade(&node));
+            owner.rw(&self.node.state).subscribers.push(Box::new(subscriber));
+        } else {
+            state.subscribers.push(Box::new(subscriber));
+        }
+    }
+}
+
+impl<G, T> Clone for Derived<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { node : self.node.clone() }
+    }
+}
+
+/// Holds the owner while a [`Derived`] value is computed, and records every
+/// [`Signal`] and [`Derived`] value that is read through it.
+///
+/// [`Signal`]: ./struct.Signal.html
+/// [`Derived`]: ./struct.Derived.html
+pub struct Reader<'a, G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    owner : &'a mut G::Owner,
+    dependencies : Dependencies<G::Owner>
+}
+
+impl<G> Reader<'_, G>
+where
+    G : CellGroup + 'static,
+    G::Owner : GroupOwner
+{
+    /// Reads a signal, and records it as a dependency.
+    pub fn get<'s, T : 'static>(&'s mut self, signal : &'s Signal<G, T>) -> &'s T {
+        let node : Rc<dyn Node<G::Owner>> = signal.node.clone();
+        self.dependencies.push((node, signal.version(self.owner)));
+        signal.get(self.owner)
+    }
+
+    /// Reads a derived value, computing it again if needed, and records it
+    /// as a dependency.
+    pub fn get_derived<'s, T : 'static>(&'s mut self, derived : &'s Derived<G, T>) -> &'s T {
+        let node : Rc<dyn Node<G::Owner>> = derived.node.clone();
+        let version = node.refresh(self.owner);
+        self.dependencies.push((node, version));
+        derived.get(self.owner)
+    }
+
+    /// Returns the owner, for reading cells of the group which are not
+    /// tracked as dependencies.
+    #[inline]
+    pub fn owner(&self) -> &G::Owner {
+        self.owner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+    use std::cell::{Cell, RefCell};
+
+    def_cells! {
+        [mod] react_grp: TLCellUniGrp;
+        [mod] react_t_grp: TCellPvtGrp;
+    }
+
+    #[test]
+    fn derived_tests() {
+        let mut owner = react_grp::new_uniform_owner();
+        let reactor: Reactor<react_grp::UniMarker> = Reactor::new();
+        let (a, b, pick_a) = (reactor.signal(2), reactor.signal(3), reactor.signal(true));
+        let runs = Rc::new(Cell::new(0));
+
+        let picked = reactor.derived({
+            let (a, b, pick_a, runs) = (a.clone(), b.clone(), pick_a.clone(), runs.clone());
+            move |reader| {
+                runs.set(runs.get() + 1);
+                if *reader.get(&pick_a) { *reader.get(&a) } else { *reader.get(&b) }
+            }
+        });
+        let doubled = reactor.derived({
+            let picked = picked.clone();
+            move |reader| reader.get_derived(&picked) * 2
+        });
+
+        assert_eq!(*doubled.get(&mut owner), 4);
+        assert_eq!(*doubled.get(&mut owner), 4);
+        assert_eq!(runs.get(), 1);
+
+        b.set(&mut owner, 30);
+        assert_eq!(*doubled.get(&mut owner), 4);
+        assert_eq!(runs.get(), 1);
+
+        pick_a.set(&mut owner, false);
+        assert_eq!(*doubled.get(&mut owner), 60);
+        a.update(&mut owner, |a| *a += 1);
+        assert_eq!(*doubled.get(&mut owner), 60);
+        assert_eq!((runs.get(), picked.version(&owner)), (2, 2));
+    }
+
+    #[test]
+    fn subscriber_tests() {
+        let mut owner = react_t_grp::new_private_owner();
+        let reactor: Reactor<react_t_grp::PvtMarker> = Reactor::default();
+        let (width, height) = (reactor.signal(2u32), reactor.signal(3u32));
+        let area = reactor.derived({
+            let (width, height) = (width.clone(), height.clone());
+            move |reader| *reader.get(&width) * *reader.get(&height)
+        });
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let seen_area = seen.clone();
+        let watched = area.clone();
+        area.subscribe(&mut owner, move |owner| seen_area.borrow_mut().push(*watched.get(owner)));
+        let (seen_width, doubled) = (seen.clone(), height.clone());
+        width.subscribe(&mut owner, move |owner| {
+            seen_width.borrow_mut().push(0);
+            if *doubled.get(owner) < 10 {
+                doubled.update(owner, |height| *height *= 2);
+            }
+        });
+
+        reactor.write(&mut owner, |owner| {
+            width.set(owner, 4);
+            width.set(owner, 5);
+        });
+        assert_eq!(*seen.borrow(), [0, 30]);
+
+        reactor.flush(&mut owner);
+        height.set(&mut owner, 6);
+        assert_eq!(height.version(&owner), 2);
+        reactor.flush(&mut owner);
+        assert_eq!(*seen.borrow(), [0, 30]);
+    }
+
+    #[test]
+    fn derived_panic_test() {
+        let mut owner = react_grp::new_uniform_owner();
+        let reactor: Reactor<react_grp::UniMarker> = Reactor::new();
+        let divisor = reactor.signal(0u32);
+        let quotient = reactor.derived({
+            let divisor = divisor.clone();
+            move |reader| 12 / *reader.get(&divisor)
+        });
+
+        let failed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| *quotient.get(&mut owner)));
+        assert!(failed.is_err());
+        divisor.set(&mut owner, 4);
+        assert_eq!(*quotient.get(&mut owner), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "a derived value cannot depend on itself")]
+    fn derived_cycle_test() {
+        let mut owner = react_grp::new_uniform_owner();
+        let reactor: Reactor<react_grp::UniMarker> = Reactor::new();
+        let slot: Rc<RefCell<Option<Derived<react_grp::UniMarker, u8>>>> = Rc::new(RefCell::new(None));
+        let inner = slot.clone();
+        let looped = reactor.derived(move |reader| {
+            let looped = inner.borrow().clone().unwrap();
+            *reader.get_derived(&looped)
+        });
+        *slot.borrow_mut() = Some(looped.clone());
+        looped.get(&mut owner);
+    }
+}
@@ -0,0 +1,415 @@
+//! A scheduler which holds the owners of several groups, and lends them to
+//! systems according to the groups that each system declares.
+//!
+//! Each system is registered with a [`Claim`], which is a [`Read`] or
+//! [`Write`] of a group, or a tuple of them, such as
+//! `(Read<ui::UniMarker>, Write<world::UniMarker>)`. The system is then
+//! run with exactly the owners that it claimed, as `&Owner` for reads and
+//! `&mut Owner` for writes:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::scheduler::{Read, Scheduler, Write};
+//!
+//! def_cells! {
+//!     [pub mod] input: TCellUniGrp;
+//!     [pub mod] world: TCellUniGrp;
+//! }
+//!
+//! let speed = input::new_uniform_cell(2);
+//! let position = world::new_uniform_cell(0);
+//!
+//! let mut scheduler = Scheduler::new();
+//! scheduler.add_group::<input::UniMarker>();
+//! scheduler.add_group::<world::UniMarker>();
+//! scheduler.add_system::<(Read<input::UniMarker>, Write<world::UniMarker>), _>(
+//!     "movement",
+//!     move |(input, world)| *position.rw(world) += *speed.ro(input)
+//! );
+//! scheduler.run();
+//! scheduler.run();
+//! ```
+//!
+//! Systems run in the order that they were registered. With
+//! [`run_parallel`], each run of consecutive systems which only read is
+//! spread across the threads of a [`std::thread::scope`], where they share
+//! the owners that they read. Since owners are shared across threads, the
+//! scheduler only holds owners which are `Send` and `Sync`, such as those
+//! of [`TCell`] groups. This applies even to a scheduler that is only ever
+//! [`run`] on one thread, so the owners of [`TLCell`] groups can not be
+//! held, and have to be passed to their code by hand:
+//!
+//! ```rust,compile_fail
+//! # use cell_wrappers::*;
+//! use cell_wrappers::scheduler::Scheduler;
+//!
+//! def_cells! {
+//!     [pub mod] local: TLCellUniGrp;
+//! }
+//!
+//! let mut scheduler = Scheduler::new();
+//! scheduler.add_group::<local::UniMarker>();
+//! ```
+//!
+//! [`Claim`]: ./trait.Claim.html
+//! [`Read`]: ./struct.Read.html
+//! [`Write`]: ./struct.Write.html
+//! [`run`]: ./struct.Scheduler.html#method.run
+//! [`run_parallel`]: ./struct.Scheduler.html#method.run_parallel
+//! [`std::thread::scope`]: https://doc.rust-lang.org/std/thread/fn.scope.html
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+//! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+
+use std::any::{type_name, Any, TypeId};
+use std::cell::UnsafeCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::CellGroup;
+
+// An owner held by the scheduler.
+struct Slot(UnsafeCell<Box<dyn Any + Send + Sync>>);
+
+// SAFETY: The owner in a slot is only borrowed mutably by a system which
+// claimed it with a `Write`. Such systems run alone, while the scheduler is
+// borrowed mutably, and systems which share a slot across threads only
+// borrow it immutably. The owner itself is `Send` and `Sync`.
+unsafe impl Sync for Slot {}
+
+/// The owners held by a [`Scheduler`], which its systems' [`Claim`]s are
+/// fetched from.
+///
+/// [`Scheduler`]: ./struct.Scheduler.html
+/// [`Claim`]: ./trait.Claim.html
+pub struct Owners {
+    slots : HashMap<TypeId, Slot>
+}
+
+impl Owners {
+    fn slot<G : 'static>(&self) -> &Slot {
+        self.slots.get(&TypeId::of::<G>()).expect("claimed groups were checked at registration")
+    }
+}
+
+/// Claims shared access to the owner of the group `G`.
+pub struct Read<G>(PhantomData<fn() -> G>);
+
+/// Claims mutable access to the owner of the group `G`.
+pub struct Write<G>(PhantomData<fn() -> G>);
+
+/// This `trait` describes the owners that a system claims, and how they are
+/// passed to it. It is implemented for [`Read`] and [`Write`], and for
+/// tuples of up to eight claims.
+///
+/// # Safety
+///
+/// [`groups`] must list every group whose owner [`fetch`] borrows, and
+/// whether it borrows that owner mutably, since the scheduler relies on
+/// that list to keep the borrows apart.
+///
+/// [`Read`]: ./struct.Read.html
+/// [`Write`]: ./struct.Write.html
+/// [`groups`]: #tymethod.groups
+/// [`fetch`]: #tymethod.fetch
+pub unsafe trait Claim {
+    type Item<'a>;
+
+    /// Adds the `TypeId` and name of each claimed group to `groups`, along
+    /// with whether it is claimed for writing.
+    fn groups(groups : &mut Vec<(TypeId, &'static str, bool)>);
+
+    /// Borrows the claimed owners from `owners`.
+    ///
+    /// # Safety
+    ///
+    /// While the result is alive, no owner that is claimed for writing may
+    /// be borrowed anywhere else, and no owner that is claimed for reading
+    /// may be borrowed mutably anywhere else.
+    unsafe fn fetch(owners : &Owners) -> Self::Item<'_>;
+}
+
+unsafe impl<G> Claim for Read<G>
+where
+    G : CellGroup + 'static,
+    G::Owner : 'static
+{
+    type Item<'a> = &'a G::Owner;
+
+    #[inline]
+    fn groups(groups : &mut Vec<(TypeId, &'static str, bool)>) {
+        groups.push((TypeId::of::<G>(), type_name::<G>(), false));
+    }
+
+    #[inline]
+    unsafe fn fetch(owners : &Owners) -> &G::Owner {
+        let owner = unsafe { &*owners.slot::<G>().0.get() };
+        owner.downcast_ref().expect("each slot holds the owner of its group")
+    }
+}
+
+unsafe impl<G> Claim for Write<G>
+where
+    G : CellGroup + 'static,
+    G::Owner : 'static
+{
+    type Item<'a> = &'a mut G::Owner;
+
+    #[inline]
+    fn groups(groups : &mut Vec<(TypeId, &'static str, bool)>) {
+        groups.push((TypeId::of::<G>(), type_name::<G>(), true));
+    }
+
+    #[inline]
+    unsafe fn fetch(owners : &Owners) -> &mut G::Owner {
+        let owner = unsafe { &mut *owners.slot::<G>().0.get() };
+        owner.downcast_mut().expect("each slot holds the owner of its group")
+    }
+}
+
+macro_rules! impl_claim_tuple {
+    ( $( $claim:ident ),* ) => {
+        unsafe impl< $( $claim : Claim ),* > Claim for ( $( $claim , )* ) {
+            type Item<'a> = ( $( $claim::Item<'a> , )* );
+
+            #[inline]
+            #[allow(unused_variables)]
+            fn groups(groups : &mut Vec<(TypeId, &'static str, bool)>) {
+                $( $claim::groups(groups); )*
+            }
+
+            #[inline]
+            #[allow(unused_variables, clippy::unused_unit)]
+            unsafe fn fetch(owners : &Owners) -> Self::Item<'_> {
+                ( $( unsafe { $claim::fetch(owners) } , )* )
+            }
+        }
+    };
+}
+
+impl_claim_tuple!();
+impl_claim_tuple!(A);
+impl_claim_tuple!(A, B);
+impl_claim_tuple!(A, B, C);
+impl_claim_tuple!(A, B, C, D);
+impl_claim_tuple!(A, B, C, D, E);
+impl_claim_tuple!(A, B, C, D, E, F);
+impl_claim_tuple!(A, B, C, D, E, F, G);
+impl_claim_tuple!(A, B, C, D, E, F, G, H);
+
+struct System {
+    name : &'static str,
+    read_only : bool,
+    run : Box<dyn FnMut(&Owners) + Send>
+}
+
+/// Holds the owners of several groups, and runs systems with the owners
+/// that they claim.
+pub struct Scheduler {
+    owners : Owners,
+    systems : Vec<System>
+}
+
+impl Scheduler {
+    #[inline]
+    pub fn new() -> Self {
+        Self { owners : Owners { slots : HashMap::new() }, systems : Vec::new() }
+    }
+
+    /// Creates an owner of the group `G`, and holds it for the systems which
+    /// claim `G`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scheduler already holds an owner of `G`.
+    #[inline]
+    pub fn add_group<G>(&mut self)
+    where
+        G : CellGroup + 'static,
+        G::Owner : Send + Sync + 'static
+    {
+        self.add_owner::<G>(G::new_owner());
+    }
+
+    /// Holds `owner` for the systems which claim the group `G`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scheduler already holds an owner of `G`.
+    pub fn add_owner<G>(&mut self, owner : G::Owner)
+    where
+        G : CellGroup + 'static,
+        G::Owner : Send + Sync + 'static
+    {
+        let slot = Slot(UnsafeCell::new(Box::new(owner)));
+        assert!(
+            self.owners.slots.insert(TypeId::of::<G>(), slot).is_none(),
+            "the scheduler already holds an owner of `{}`", type_name::<G>()
+        );
+    }
+
+    /// Returns the owner of the group `G`, between runs.
+    pub fn owner_mut<G>(&mut self) -> Option<&mut G::Owner>
+    where
+        G : CellGroup + 'static,
+        G::Owner : 'static
+    {
+        self.owners.slots.get_mut(&TypeId::of::<G>())?.0.get_mut().downcast_mut()
+    }
+
+    /// Registers a system, named `name`, which is run with the owners that
+    /// `C` claims.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` claims a group more than once, or claims a group that
+    /// the scheduler holds no owner of.
+    pub fn add_system<C, F>(&mut self, name : &'static str, mut system : F)
+    where
+        C : Claim + 'static,
+        F : for<'a> FnMut(C::Item<'a>) + Send + 'static
+    {
+        let mut groups = Vec::new();
+        C::groups(&mut groups);
+        for (index, (id, group, _)) in groups.iter().enumerate() {
+            assert!(
+                !groups[..index].iter().any(|(other, _, _)| other == id),
+                "system `{}` claims `{}` more than once", name, group
+            );
+        }
+        for (id, group, _) in groups.iter() {
+            assert!(
+                self.owners.slots.contains_key(id),
+                "system `{}` claims `{}`, which the scheduler holds no owner of", name, group
+            );
+        }
+        self.systems.push(System {
+            name,
+            read_only : groups.iter().all(|(_, _, write)| !write),
+            // SAFETY: The claims of the system were checked to be distinct,
+            // so none of its owners are borrowed twice, and it only runs
+            // alongside other systems when none of them write.
+            run : Box::new(move |owners| system(unsafe { C::fetch(owners) }))
+        });
+    }
+
+    /// Returns the names of the systems, in the order that they run.
+    pub fn systems(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.systems.iter().map(|system| system.name)
+    }
+
+    /// Runs every system once, in the order that they were registered.
+    pub fn run(&mut self) {
+        for system in self.systems.iter_mut() {
+            (system.run)(&self.owners);
+        }
+    }
+
+    /// Runs every system once, in the order that they were registered,
+    /// except that each run of consecutive systems which only read is run
+    /// at the same time, on scoped threads.
+    pub fn run_parallel(&mut self) {
+        let owners = &self.owners;
+        let mut systems = self.systems.as_mut_slice();
+        while let Some(first) = systems.first() {
+            let batch = if first.read_only {
+                systems.iter().take_while(|system| system.read_only).count()
+            } else {
+                1
+            };
+            let (current, rest) = systems.split_at_mut(batch);
+            systems = rest;
+            match current {
+                [system] => (system.run)(owners),
+                readers => std::thread::scope(|scope| {
+                    for system in readers {
+                        scope.spawn(move || (system.run)(owners));
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl Default for Scheduler {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::def_cells;
+    use std::sync::{Arc, Barrier, Mutex};
+
+    def_cells! {
+        [mod] sched_input: TCellUniGrp;
+        [mod] sched_world: TCellAccGrp;
+        [mod] sched_stats: TCellUniGrp;
+    }
+
+    #[test]
+    fn scheduler_tests() {
+        let speed = Arc::new(sched_input::new_uniform_cell(3));
+        let position = Arc::new(sched_world::new_public_cell(0));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let mut scheduler = Scheduler::default();
+        scheduler.add_group::<sched_input::UniMarker>();
+        scheduler.add_group::<sched_world::PubMarker>();
+        scheduler.add_system::<(Read<sched_input::UniMarker>, Write<sched_world::PubMarker>), _>("move", {
+            let (speed, position) = (speed.clone(), position.clone());
+            move |(input, world)| *position.rw(world) += *speed.ro(input)
+        });
+
+        // Both readers wait on each other, so they must run at the same time.
+        let barrier = Arc::new(Barrier::new(2));
+        for name in ["log", "draw"] {
+            let (barrier, seen, position) = (barrier.clone(), seen.clone(), position.clone());
+            scheduler.add_system::<(Read<sched_world::PubMarker>,), _>(name, move |(world,)| {
+                barrier.wait();
+                seen.lock().unwrap().push((name, *position.ro(world)));
+            });
+        }
+        scheduler.add_system::<Write<sched_input::UniMarker>, _>("slow down", {
+            let speed = speed.clone();
+            move |input| *speed.rw(input) -= 1
+        });
+        scheduler.add_system::<(), _>("idle", |()| {});
+
+        assert_eq!(scheduler.systems().collect::<Vec<_>>(), ["move", "log", "draw", "slow down", "idle"]);
+        scheduler.run_parallel();
+        scheduler.run_parallel();
+
+        let mut seen = seen.lock().unwrap().clone();
+        seen.sort();
+        assert_eq!(seen, [("draw", 3), ("draw", 5), ("log", 3), ("log", 5)]);
+
+        let world = scheduler.owner_mut::<sched_world::PubMarker>().unwrap();
+        assert_eq!(*position.ro(world), 5);
+        assert!(scheduler.owner_mut::<sched_stats::UniMarker>().is_none());
+    }
+
+    #[test]
+    fn sequential_run_test() {
+        let mut scheduler = Scheduler::new();
+        let total = Arc::new(sched_stats::new_uniform_cell(0));
+        scheduler.add_owner::<sched_stats::UniMarker>(sched_stats::new_uniform_owner());
+        scheduler.add_system::<Write<sched_stats::UniMarker>, _>("count", {
+            let total = total.clone();
+            move |stats| *total.rw(stats) += 1
+        });
+        scheduler.run();
+        scheduler.run();
+        let stats = scheduler.owner_mut::<sched_stats::UniMarker>().unwrap();
+        assert_eq!(*total.ro(stats), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "claims `cell_wrappers::scheduler::tests::sched_input::UniMarker` more than once")]
+    fn conflicting_claims_test() {
+        let mut scheduler = Scheduler::new();
+        scheduler.add_system::<(Read<sched_input::UniMarker>, Write<sched_input::UniMarker>), _>("both", |_| {});
+    }
+}
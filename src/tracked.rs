@@ -0,0 +1,253 @@
+//! Cells which count the times that they are borrowed mutably, so that
+//! data derived from them is only recomputed after they change.
+//!
+//! A [`TrackedCell`] is kept in a cell of the group `G`, which is the
+//! marker of a [`TCell`] or [`TLCell`] group, such as `my_grp::UniMarker`.
+//! Every [`rw`] through the group's owner, including those made by the
+//! `mut` forms of [`c_scp`], gives the cell a new version and marks it as
+//! dirty, while [`ro`] leaves it as it was:
+//!
+//! ```rust
+//! # use cell_wrappers::*;
+//! use cell_wrappers::tracked::TrackedCell;
+//!
+//! def_cells! {
+//!     [pub mod] scene: TCellUniGrp;
+//! }
+//!
+//! let mut owner = scene::new_uniform_owner();
+//! let mesh: TrackedCell<scene::UniMarker, Vec<u32>> = TrackedCell::new(vec![1, 2]);
+//! assert_eq!(mesh.version(&owner), 0);
+//!
+//! c_scp! {
+//!     use &mut owner => ( mesh => mut vertices ) {
+//!         vertices.push(3);
+//!     }
+//! }
+//! assert_eq!(mesh.version(&owner), 1);
+//! assert!(mesh.take_dirty(&mut owner));
+//! assert!(!mesh.take_dirty(&mut owner));
+//! ```
+//!
+//! Cells made by one [`Tracker`] draw their versions from a shared counter,
+//! so the tracker can tell whether any of them changed since a version
+//! that was seen earlier.
+//!
+//! [`TCell`]: https://docs.rs/qcell/latest/qcell/struct.TCell.html
+//! [`TLCell`]: https://docs.rs/qcell/latest/qcell/struct.TLCell.html
+//! [`rw`]: ./struct.TrackedCell.html#method.rw
+//! [`ro`]: ./struct.TrackedCell.html#method.ro
+//! [`c_scp`]: ../macro.c_scp.html
+
+use std::rc::Rc;
+
+use crate::collections::CellOf;
+use crate::{CellGroup, GroupOwner};
+
+struct Tracked<T> {
+    value : T,
+    version : u64,
+    dirty : bool
+}
+
+/// A cell of the group `G`, which is given a new version whenever it is
+/// borrowed mutably.
+pub struct TrackedCell<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    tracked : CellOf<G, Tracked<T>>,
+    counter : Rc<CellOf<G, u64>>
+}
+
+impl<G, T> TrackedCell<G, T>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates a `TrackedCell` which counts its own versions, starting at
+    /// version `0`.
+    #[inline]
+    pub fn new(value : T) -> Self {
+        Self::with_counter(value, Rc::new(G::Owner::new_cell(0)))
+    }
+
+    fn with_counter(value : T, counter : Rc<CellOf<G, u64>>) -> Self {
+        let tracked = Tracked { value, version : 0, dirty : false };
+        Self { tracked : G::Owner::new_cell(tracked), counter }
+    }
+
+    #[inline]
+    pub fn ro<'a>(&'a self, owner : &'a G::Owner) -> &'a T {
+        &owner.ro(&self.tracked).value
+    }
+
+    /// Gives the cell a new version, marks it as dirty, and returns its
+    /// contents mutably.
+    pub fn rw<'a>(&'a self, owner : &'a mut G::Owner) -> &'a mut T {
+        let counter = owner.rw(&*self.counter);
+        *counter += 1;
+        let version = *counter;
+        let tracked = owner.rw(&self.tracked);
+        tracked.version = version;
+        tracked.dirty = true;
+        &mut tracked.value
+    }
+
+    /// Returns the version that the cell was given by its latest change,
+    /// or `0` if it has never changed.
+    #[inline]
+    pub fn version(&self, owner : &G::Owner) -> u64 {
+        owner.ro(&self.tracked).version
+    }
+
+    #[inline]
+    pub fn is_dirty(&self, owner : &G::Owner) -> bool {
+        owner.ro(&self.tracked).dirty
+    }
+
+    /// Clears the dirty mark, and returns whether it was set.
+    #[inline]
+    pub fn take_dirty(&self, owner : &mut G::Owner) -> bool {
+        std::mem::take(&mut owner.rw(&self.tracked).dirty)
+    }
+
+    /// Checks whether the cell has changed since it had `version`.
+    #[inline]
+    pub fn changed_since(&self, owner : &G::Owner, version : u64) -> bool {
+        self.version(owner) > version
+    }
+
+    /// Consumes the `TrackedCell`, and returns its contents.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        G::Owner::into_inner(self.tracked).value
+    }
+}
+
+/// Makes [`TrackedCell`]s which share a version counter, so that a change
+/// to any of them can be noticed from a single version.
+///
+/// ```rust
+/// # use cell_wrappers::*;
+/// use cell_wrappers::tracked::Tracker;
+///
+/// def_cells! {
+///     [pub mod] scene: TCellUniGrp;
+/// }
+///
+/// let mut owner = scene::new_uniform_owner();
+/// let tracker: Tracker<scene::UniMarker> = Tracker::new();
+/// let (width, height) = (tracker.new_cell(640), tracker.new_cell(480));
+///
+/// let seen = tracker.version(&owner);
+/// assert!(!tracker.changed_since(&owner, seen));
+/// *height.rw(&mut owner) = 600;
+/// assert!(tracker.changed_since(&owner, seen));
+/// assert!(!width.changed_since(&owner, seen));
+/// ```
+///
+/// [`TrackedCell`]: ./struct.TrackedCell.html
+pub struct Tracker<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    counter : Rc<CellOf<G, u64>>
+}
+
+impl<G> Tracker<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    /// Creates a `Tracker`, starting at version `0`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { counter : Rc::new(G::Owner::new_cell(0)) }
+    }
+
+    /// Creates a [`TrackedCell`] which draws its versions from this tracker.
+    ///
+    /// [`TrackedCell`]: ./struct.TrackedCell.html
+    #[inline]
+    pub fn new_cell<T>(&self, value : T) -> TrackedCell<G, T> {
+        TrackedCell::with_counter(value, self.counter.clone())
+    }
+
+    /// Returns the version of the latest change to any of the tracker's
+    /// cells, or `0` if none of them have changed.
+    #[inline]
+    pub fn version(&self, owner : &G::Owner) -> u64 {
+        *owner.ro(&*self.counter)
+    }
+
+    /// Checks whether any of the tracker's cells have changed since the
+    /// tracker had `version`.
+    #[inline]
+    pub fn changed_since(&self, owner : &G::Owner, version : u64) -> bool {
+        self.version(owner) > version
+    }
+}
+
+impl<G> Default for Tracker<G>
+where
+    G : CellGroup,
+    G::Owner : GroupOwner
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{c_scp, def_cells};
+
+    def_cells! {
+        [mod] track_grp: TCellUniGrp;
+        [mod] track_tl_grp: TLCellAccGrp;
+    }
+
+    #[test]
+    fn tracked_cell_tests() {
+        let mut owner = track_grp::new_uniform_owner();
+        let cell: TrackedCell<track_grp::UniMarker, u8> = TrackedCell::new(1);
+
+        assert_eq!(*cell.ro(&owner), 1);
+        assert!(!cell.is_dirty(&owner));
+        *cell.rw(&mut owner) += 1;
+        c_scp! {
+            use &mut owner => ( cell => *mut copied ) {
+                copied += 1;
+                assert_eq!(copied, 3);
+            }
+        }
+        assert_eq!((cell.version(&owner), *cell.ro(&owner)), (2, 2));
+        assert!(cell.changed_since(&owner, 1));
+        assert!(cell.take_dirty(&mut owner));
+        assert!(!cell.is_dirty(&owner));
+        assert_eq!(cell.into_inner(), 2);
+    }
+
+    #[test]
+    fn tracker_tests() {
+        let mut owner = track_tl_grp::new_public_owner();
+        let tracker: Tracker<track_tl_grp::PubMarker> = Tracker::default();
+        let names = tracker.new_cell(vec!["a"]);
+        let count = tracker.new_cell(0usize);
+
+        names.rw(&mut owner).push("b");
+        *count.rw(&mut owner) = names.ro(&owner).len();
+        let seen = tracker.version(&owner);
+        assert_eq!((names.version(&owner), count.version(&owner), seen), (1, 2, 2));
+
+        names.rw(&mut owner).clear();
+        assert!(tracker.changed_since(&owner, seen));
+        assert!(names.changed_since(&owner, seen) && !count.changed_since(&owner, seen));
+        assert!(names.is_dirty(&owner) && count.is_dirty(&owner));
+    }
+}
@@ -0,0 +1,568 @@
+//! Invokes every macro form from outside of `cell_wrappers`, with no glob
+//! imports, to make sure that each expansion only relies on `$crate` paths.
+//!
+//! As an integration test, this still links `qcell`, so it is also built
+//! as a test of the crate in `tests/downstream`, which depends on nothing
+//! but `cell_wrappers`. The `downstream_crate` test runs it there.
+
+#![cfg(feature = "std")]
+
+use cell_wrappers::{
+    c_scp, c_txn, dbg_cell, def_cells, fmt_cells, impl_get_pub, impl_get_pvt, impl_get_uni,
+    new_t_cell_type, new_t_group, new_t_marker_type, new_t_owner_type,
+    new_group, new_tl_cell_type, new_tl_group, new_tl_marker_type, new_tl_owner_type,
+};
+
+def_cells! {
+    [pub mod] t_uni: TCellUniGrp;
+    [pub mod] t_acc: TCellAccGrp;
+    [pub mod] t_pub: TCellPubGrp;
+    [pub mod] t_pvt: TCellPvtGrp;
+    [pub mod] tl_uni: TLCellUniGrp;
+    [pub mod] tl_acc: TLCellAccGrp;
+    [pub mod] tl_pub: TLCellPubGrp;
+    [pub mod] tl_pvt: TLCellPvtGrp;
+    [pub mod] q_uni: QCellUniGrp;
+    [pub mod] q_acc: QCellAccGrp;
+    [pub mod] q_pub: QCellPubGrp;
+    [pub mod] q_pvt: QCellPvtGrp;
+    [pub mod] l_uni: LCellUniGrp;
+    [pub mod] l_acc: LCellAccGrp;
+    [pub mod] l_pub: LCellPubGrp;
+    [pub mod] l_pvt: LCellPvtGrp;
+    #[allow(dead_code)]
+    [mod] clustered::{
+        inner::{t_grp: TCellUniGrp},
+        tl_grp: TLCellAccGrp
+    };
+    [pub mod] shard: TCellUniGrp<const ID: usize>;
+    [pub mod] tagged::{by_type: TLCellPubGrp<Tag>};
+    [pub mod] app::{
+        #[cfg(feature = "std")] [pub(crate) mod] net: TCellPubGrp,
+        #[allow(dead_code)] [mod] io::{disk: TLCellUniGrp},
+        ui: TLCellUniGrp
+    };
+    [pub mod] audio: TCellAccGrp as {
+        pub: (MixerMarker, MixerOwner, MixerCell),
+        pvt: (DeviceMarker, DeviceOwner, DeviceCell)
+    };
+    [pub mod] wrapped: TLCellPubGrp with { cells: newtype };
+    [pub mod] metered: TLCellUniGrp with { owners: newtype, hooks: (crate::Meter) };
+    [pub mod] tree: TCellUniGrp as {
+        uni: (NodeMarker, NodeOwner, NodeCell, RcNode, ArcNode, WeakNode)
+    };
+}
+
+std::thread_local! {
+    static METER_LOG: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+struct Meter;
+
+impl cell_wrappers::OwnerHooks for Meter {
+    fn on_acquire(group: &'static str) {
+        METER_LOG.with(|log| log.borrow_mut().push(format!("acquire {}", group)));
+    }
+    fn on_release(group: &'static str) {
+        METER_LOG.with(|log| log.borrow_mut().push(format!("release {}", group)));
+    }
+}
+
+new_t_group!(FamOwner[FamMarker] => FamCell<T>);
+new_t_group!(#[allow(dead_code)] pub FamPubOwner[FamPubMarker] => FamPubCell);
+new_tl_group!(FamTlOwner[FamTlMarker] => FamTlCell<T>);
+new_t_group!(pvt FamPvtOwner[FamPvtMarker] => FamPvtCell<T>);
+new_tl_group! {
+    access: public,
+    marker: FamAccMarker,
+    owner: FamAccOwner,
+    cell: FamAccCell<T>
+}
+
+new_t_group! {
+    marker: StructMarker,
+    pub owner: StructOwner,
+    #[allow(dead_code)] pub cell: StructCell<T>
+}
+new_tl_group! {
+    pub owner: StructTlOwner,
+    #[allow(dead_code)]
+    pub marker: StructTlMarker,
+    cell: StructTlCell<T>,
+}
+new_tl_group! {
+    owner: StructTlOwner2,
+    cell: StructTlCell2<T>,
+    marker: StructTlMarker2
+}
+
+new_t_marker_type!(PartMarker);
+new_t_owner_type!(pub PartOwner[PartMarker]);
+new_t_cell_type!(pub PartCell<T>[PartMarker]);
+new_tl_marker_type!(#[allow(dead_code)] pub struct PartTlMarker);
+new_tl_owner_type!(pub type PartTlOwner[PartTlMarker]);
+new_tl_cell_type!(PartTlCell[PartTlMarker]<T>, owner: PartTlOwner);
+new_tl_cell_type!(pub PartTlPair<'a, K: Clone, V: ?Sized>[PartTlMarker] => (&'a K, Box<V>));
+
+new_group!(impl = auto, access = pub, UniOwner2[UniMarker2] => UniCell2<T>);
+new_group!(impl = Q, QOwner[QMarker] => QCell<T>);
+new_group!(impl = L, access = pvt, LOwner[LMarker] => LCell);
+new_group! {
+    impl = TL,
+    access: public,
+    marker: GrpMarker,
+    owner: GrpOwner,
+    cell: GrpCell<T>
+}
+
+struct Holder {
+    uni_cell: t_uni::UniCell<i32>,
+    pub_cell: t_acc::PubCell<i32>,
+    pvt_cell: t_acc::PvtCell<i32>,
+}
+
+impl_get_uni!(Holder => t_uni::UniOwner);
+impl_get_pub!(Holder => t_acc::PubOwner);
+impl_get_pvt!(Holder => t_acc::PvtOwner);
+
+impl Holder {
+    fn bump_all(&self) -> (i32, i32, i32) {
+        let (uni_total, pub_total, pvt_total): (i32, i32, i32);
+
+        c_scp! {
+            use [self] => (
+                self.uni_cell => mut uni_cont
+            ) {
+                *uni_cont += 1;
+            }
+        }
+        c_scp! {
+            let pub_owner = [self] => (
+                self.pub_cell => *out pub_total
+            ) {
+                assert_eq!(*self.pub_cell.ro(pub_owner), 0);
+            }
+        }
+        c_scp! {
+            use [self] => (
+                self.pvt_cell => &mut pvt_cont
+            ) {
+                *pvt_cont += 3;
+            }
+        }
+        c_scp! {
+            use [self] => (
+                self.uni_cell => *out uni_total
+            ) {}
+        }
+        c_scp! {
+            use [self] => (
+                self.pvt_cell => *out pvt_total
+            ) {}
+        }
+
+        (uni_total, pub_total, pvt_total)
+    }
+}
+
+#[test]
+fn def_cells_groups_test() {
+    assert_eq!(t_uni::get_cell_impl(), cell_wrappers::CellImpl::T);
+    assert_eq!(tl_acc::get_cell_impl(), cell_wrappers::CellImpl::TL);
+    assert_eq!(q_pub::get_cell_impl(), cell_wrappers::CellImpl::Q);
+    assert_eq!(l_pvt::get_cell_impl(), cell_wrappers::CellImpl::L);
+
+    assert!(t_uni::has_uniform_access() && !t_uni::has_public_access());
+    assert!(t_acc::has_private_access() && t_acc::has_public_access());
+    assert!(t_pub::has_public_access() && !t_pub::has_private_access());
+    assert!(t_pvt::has_private_access() && !t_pvt::has_uniform_access());
+    assert!(tl_uni::has_uniform_access() && tl_pub::has_public_access());
+    assert!(tl_pvt::has_private_access() && !tl_acc::has_uniform_access());
+    assert!(q_uni::has_uniform_access() && q_acc::has_public_access());
+    assert!(q_pvt::has_private_access() && !q_pub::has_private_access());
+    assert!(l_uni::has_uniform_access() && l_acc::has_private_access());
+    assert!(l_pub::has_public_access() && !l_pvt::has_public_access());
+
+    let t_owner = t_pub::new_public_owner();
+    let t_cell = t_pub::new_public_cell(1);
+    assert_eq!(*t_cell.ro(&t_owner), 1);
+
+    let tl_owner = tl_pvt::new_private_owner();
+    let tl_cell: tl_pvt::PvtCell<i32> = tl_pvt::new_private_cell(2);
+    assert_eq!(*tl_cell.ro(&tl_owner), 2);
+
+    let q_owner = q_acc::new_private_owner();
+    let q_cell = q_acc::new_private_cell(&q_owner, 3);
+    assert_eq!(*q_cell.ro(&q_owner), 3);
+
+    l_acc::new_public_owner(|l_owner| {
+        let l_cell = l_acc::new_public_cell(4);
+        assert_eq!(*l_cell.ro(&l_owner), 4);
+    });
+
+    let cluster_owner = clustered::inner::t_grp::new_uniform_owner();
+    let cluster_cell = clustered::inner::t_grp::new_uniform_cell(5);
+    assert_eq!(*cluster_cell.ro(&cluster_owner), 5);
+    assert!(clustered::tl_grp::has_public_access());
+
+    assert!(app::net::has_public_access() && app::ui::has_uniform_access());
+}
+
+#[test]
+#[should_panic]
+fn def_cells_unavailable_owner_test() {
+    t_uni::new_private_owner();
+}
+
+#[test]
+fn families_test() {
+    let fam_owner = FamOwner::new();
+    let fam_cell = FamCell::new(1);
+    assert_eq!(*fam_cell.ro(&fam_owner), 1);
+
+    let fam_pub_cell: FamPubCell<i32> = FamPubCell::new(2);
+    assert_eq!(*fam_pub_cell.ro(&FamPubOwner::new()), 2);
+
+    let fam_tl_cell = FamTlCell::new(3);
+    assert_eq!(*fam_tl_cell.ro(&FamTlOwner::new()), 3);
+
+    let struct_cell = StructCell::new(4);
+    assert_eq!(*struct_cell.ro(&StructOwner::new()), 4);
+
+    let struct_tl_cell = StructTlCell::new(5);
+    assert_eq!(*struct_tl_cell.ro(&StructTlOwner::new()), 5);
+
+    let struct_tl_cell2 = StructTlCell2::new(6);
+    assert_eq!(*struct_tl_cell2.ro(&StructTlOwner2::new()), 6);
+
+    let part_cell = PartCell::new(7);
+    assert_eq!(*part_cell.ro(&PartOwner::new()), 7);
+
+    let part_tl_cell = PartTlCell::new(8);
+    c_scp! {
+        use _ => (
+            part_tl_cell => *cont
+        ) {
+            assert_eq!(cont, 8);
+        }
+    }
+
+    let _markers = (StructMarker, StructTlMarker, StructTlMarker2, FamMarker);
+
+    let key = 1;
+    let pair: PartTlPair<i32, dyn Fn() -> i32> = PartTlPair::new((&key, Box::new(|| 2)));
+    c_scp! {
+        use _ => (
+            pair => pair_cont
+        ) {
+            assert_eq!(*pair_cont.0 + (pair_cont.1)(), 3);
+        }
+    }
+
+    let fam_pvt_cell = FamPvtCell::new(9);
+    c_scp! {
+        use _ => (
+            fam_pvt_cell => mut cont
+        ) {
+            *cont += 1;
+        }
+    }
+    let fam_acc_cell = FamAccCell::new(10);
+    c_scp! {
+        let acc_owner = _ => (
+            fam_acc_cell => *cont
+        ) {
+            assert_eq!(cont, 10);
+            assert_eq!(*fam_pvt_cell.ro(&FamPvtOwner::new()), 10);
+            let _: &FamAccOwner = acc_owner;
+        }
+    }
+}
+
+#[test]
+fn new_group_test() {
+    let auto_cell = UniCell2::new(1);
+    c_scp! {
+        use _ => (
+            auto_cell => *cont
+        ) {
+            assert_eq!(cont, 1);
+        }
+    }
+
+    let grp_cell = GrpCell::new(2);
+    assert_eq!(*grp_cell.ro(&GrpOwner::new()), 2);
+    let _ = GrpMarker;
+
+    let q_owner = QOwner::new();
+    let q_cell: QCell<i32> = q_owner.cell(3);
+    assert_eq!(*q_cell.ro(&q_owner), 3);
+
+    LOwner::scope(|l_owner| {
+        let l_cell: LCell<i32> = LCell::new(4);
+        assert_eq!(*l_cell.ro(&l_owner), 4);
+    });
+}
+
+#[test]
+fn marker_family_test() {
+    struct TestTag;
+
+    let first: shard::UniCell<0, i32> = shard::new_uniform_cell(1);
+    let third: shard::UniCell<3, i32> = shard::new_uniform_cell(2);
+    let first_owner = shard::new_uniform_owner::<0>();
+    c_scp! {
+        use shard::UniOwner<3> => (
+            third => mut cont
+        ) {
+            *cont += *first.ro(&first_owner);
+        }
+    }
+    c_scp! {
+        use _ => (
+            third => *cont
+        ) {
+            assert_eq!(cont, 3);
+        }
+    }
+
+    let tagged_cell: tagged::by_type::PubCell<TestTag, i32> = tagged::by_type::new_public_cell(4);
+    assert_eq!(*tagged_cell.ro(&tagged::by_type::new_public_owner::<TestTag>()), 4);
+}
+
+#[test]
+fn renamed_items_test() {
+    use audio::{DeviceCell, MixerCell, MixerOwner};
+
+    let mixer_cell: MixerCell<i32> = audio::new_public_cell(1);
+    let device_cell = DeviceCell::new(2);
+    c_scp! {
+        use MixerOwner => (
+            mixer_cell => *cont
+        ) {
+            assert_eq!(cont, 1);
+        }
+    }
+    c_scp! {
+        let device_owner = _ => (
+            device_cell => *cont
+        ) {
+            assert_eq!(cont, 2);
+            let _: &audio::DeviceOwner = device_owner;
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for wrapped::PubCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PubCell").field(self.ro(&wrapped::new_public_owner())).finish()
+    }
+}
+
+#[test]
+fn newtype_cell_test() {
+    let cell: wrapped::PubCell<i32> = wrapped::PubCell::from(1);
+    assert_eq!(format!("{:?}", cell), "PubCell(1)");
+
+    let default_cell: wrapped::PubCell<i32> = Default::default();
+    c_scp! {
+        use _ => (
+            default_cell => mut cont
+        ) {
+            *cont += 2;
+        }
+    }
+    assert_eq!(default_cell.into_inner(), 2);
+}
+
+#[test]
+fn newtype_owner_test() {
+    let cell = metered::new_uniform_cell(1);
+    c_scp! {
+        use _ => (
+            cell => mut cont
+        ) {
+            *cont += 1;
+        }
+    }
+    let owner = metered::UniOwner::new();
+    assert_eq!(*cell.ro(&owner), 2);
+    assert_eq!(metered::UniOwner::GROUP, "cross_crate::metered");
+    assert_eq!(format!("{:?}", owner), "UniOwner { group: \"cross_crate::metered\" }");
+    drop(owner);
+
+    METER_LOG.with(|log| {
+        assert_eq!(*log.borrow(), [
+            "acquire cross_crate::metered",
+            "release cross_crate::metered",
+            "acquire cross_crate::metered",
+            "release cross_crate::metered",
+        ]);
+    });
+}
+
+#[test]
+fn shared_cells_test() {
+    let node: tree::RcNode<i32> = tree::new_rc_uniform_cell(1);
+    let parent: tree::WeakNode<i32> = std::rc::Rc::downgrade(&node);
+    c_scp! {
+        use tree::NodeOwner => (
+            weak parent => mut cont
+        ) {
+            *cont += 1;
+        }
+    }
+    assert_eq!(*node.ro(&tree::NodeOwner::new()), 2);
+    drop(node);
+    c_scp! {
+        use _ => (
+            weak parent => *cont
+        ) {
+            panic!("dropped cell was upgraded: {}", cont);
+        }
+    }
+
+    let tl_cell: tl_pvt::ArcPvtCell<i32> = tl_pvt::new_arc_private_cell(3);
+    let wrapped_cell: wrapped::RcPubCell<i32> = wrapped::new_rc_public_cell(4);
+    c_scp! {
+        use _ => (
+            tl_cell => *cont
+        ) {
+            assert_eq!(cont, 3);
+        }
+    }
+    c_scp! {
+        use _ => (
+            wrapped_cell => *cont
+        ) {
+            assert_eq!(cont, 4);
+        }
+    }
+
+    let mut q_owner = q_uni::new_uniform_owner();
+    let q_cell: q_uni::ArcUniCell<i32> = q_uni::new_arc_uniform_cell(&q_owner, 5);
+    *q_cell.rw(&mut q_owner) += 1;
+    assert_eq!(*q_cell.ro(&q_owner), 6);
+
+    l_uni::new_uniform_owner(|l_owner| {
+        let l_cell: l_uni::RcUniCell<'_, i32> = l_uni::new_rc_uniform_cell(7);
+        assert_eq!(*l_cell.ro(&l_owner), 7);
+    });
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Persisted {
+    #[serde(with = "cell_wrappers::cell_serde")]
+    wrapped: wrapped::PubCell<u8>,
+    #[serde(with = "cell_wrappers::cell_serde")]
+    plain: tl_acc::PubCell<u8>
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn cell_serde_test() {
+    use cell_wrappers::cell_serde::WithOwner;
+    use serde_test::{assert_ser_tokens, Token};
+
+    let wrapped_owner = wrapped::new_public_owner();
+    let plain_owner = tl_acc::new_public_owner();
+    let persisted = Persisted {
+        wrapped: wrapped::new_public_cell(1),
+        plain: tl_acc::new_public_cell(2)
+    };
+    assert_ser_tokens(
+        &WithOwner::new(&wrapped_owner, &WithOwner::new(&plain_owner, &persisted)),
+        &[
+            Token::Struct { name: "Persisted", len: 2 },
+            Token::Str("wrapped"), Token::U8(1),
+            Token::Str("plain"), Token::U8(2),
+            Token::StructEnd
+        ]
+    );
+}
+
+#[test]
+fn c_scp_forms_test() {
+    let cell = t_uni::UniCell::new(0);
+
+    c_scp! {
+        use t_uni::UniOwner => (
+            cell => mut cont
+        ) {
+            *cont += 1;
+        }
+    }
+    c_scp! {
+        let named_owner = _ => (
+            cell => *cont
+        ) {
+            assert_eq!(cont, 1);
+            assert_eq!(*cell.ro(named_owner), 1);
+        }
+    }
+
+    {
+        let mut owner = t_uni::new_uniform_owner();
+        c_scp! {
+            use &mut owner => (
+                cell => *mut cont
+            ) {
+                cont += 1;
+                assert_eq!(cont, 2);
+            }
+        }
+        let outer: i32;
+        c_scp! {
+            use &owner => (
+                cell => *out outer
+            ) {}
+        }
+        assert_eq!(outer, 1);
+    }
+
+    c_scp! {
+        use ::cell_wrappers::qcell::TCellOwner<crate::t_uni::UniMarker> => (
+            cell => *cont
+        ) {
+            assert_eq!(cont, 1);
+        }
+    }
+    c_scp! {
+        let built_owner = (<t_uni::UniMarker as cell_wrappers::CellGroup>::new_owner()) => (
+            mut cell
+        ) {
+            *cell.rw(built_owner) += 1;
+        }
+    }
+    assert_eq!(*cell.ro(&t_uni::new_uniform_owner()), 2);
+
+    let holder = Holder {
+        uni_cell: t_uni::UniCell::new(0),
+        pub_cell: t_acc::PubCell::new(0),
+        pvt_cell: t_acc::PvtCell::new(0),
+    };
+    assert_eq!(holder.bump_all(), (1, 0, 3));
+}
+
+#[test]
+fn cell_fmt_and_txn_test() {
+    let mut owner = t_uni::new_uniform_owner();
+    let hits = t_uni::new_uniform_cell(3);
+    let misses = t_uni::new_uniform_cell(1);
+
+    assert_eq!(format!("{}", fmt_cells!(owner; hits, misses)), "{hits: 3, misses: 1}");
+    let shown = dbg_cell!(owner; &hits);
+    assert_eq!(*shown.ro(&owner), 3);
+
+    let result: Result<(), i32> = c_txn! {
+        let txn_owner = &mut owner => (hits, misses => mut misses) {
+            *misses += 1;
+            let total = *misses;
+            *hits.rw(txn_owner) = 0;
+            Err(total)
+        }
+    };
+    assert_eq!(result, Err(2));
+    assert_eq!((*hits.ro(&owner), *misses.ro(&owner)), (3, 1));
+}
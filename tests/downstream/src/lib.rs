@@ -0,0 +1,38 @@
+//! A crate which depends on nothing but `cell_wrappers`, so that a macro
+//! expansion which names `qcell` directly fails to compile here. Its tests
+//! are the ones in `tests/cross_crate.rs`.
+//!
+//! Without the `std` feature, this also checks that an embedded crate can
+//! declare and use its cells.
+
+#![no_std]
+
+use cell_wrappers::def_cells;
+
+def_cells! {
+    [pub mod] scoped: LCellUniGrp;
+}
+
+#[cfg(feature = "alloc")]
+def_cells! {
+    [pub mod] queued: QCellUniGrp;
+}
+
+/// Adds one to a fresh cell of the `scoped` group, and returns its value
+/// from the owner's scope.
+pub fn scoped_total(start: u32) -> u32 {
+    scoped::new_uniform_owner(|mut owner| {
+        let cell = scoped::new_uniform_cell(start);
+        *cell.rw(&mut owner) += 1;
+        *cell.ro(&owner)
+    })
+}
+
+/// Adds one to a fresh cell of the `queued` group, and returns its value.
+#[cfg(feature = "alloc")]
+pub fn queued_total(start: u32) -> u32 {
+    let mut owner = queued::new_uniform_owner();
+    let cell = queued::new_uniform_cell(&owner, start);
+    *cell.rw(&mut owner) += 1;
+    *cell.ro(&owner)
+}
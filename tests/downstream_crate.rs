@@ -0,0 +1,31 @@
+//! Builds and tests the crate in `tests/downstream`, which depends on
+//! nothing but `cell_wrappers`, with a few sets of features.
+
+use std::path::Path;
+use std::process::Command;
+
+fn downstream_cargo(args: &[&str]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .args(args)
+        .arg("--manifest-path")
+        .arg(root.join("tests/downstream/Cargo.toml"))
+        .env("CARGO_TARGET_DIR", root.join("target/downstream"))
+        .output()
+        .expect("cargo could not be run");
+    assert!(
+        output.status.success(),
+        "`cargo {}` failed for tests/downstream:\n{}{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn downstream_crate_test() {
+    downstream_cargo(&["test"]);
+    downstream_cargo(&["test", "--features", "auto-tl,serde"]);
+    downstream_cargo(&["build", "--no-default-features"]);
+    downstream_cargo(&["build", "--no-default-features", "--features", "alloc"]);
+}